@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+use common::error::ParseError;
+use crate::days::Day;
+
+/// Which of a day's inputs an answer was recorded against.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Input {
+    Sample,
+    Real,
+}
+impl Input {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Input::Sample => "sample",
+            Input::Real => "input",
+        }
+    }
+}
+
+/// A recorded answer, or a marker that the part is still a placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Answer(String),
+    Pending,
+}
+
+/// A day's `answers.txt`.
+///
+/// Each line is `<sample|input> <part> <answer>`, with `pending` as the answer for a part that still
+/// returns [`common::answer::Answer::Unsolved`]. Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    entries: Vec<(Input, u8, Expected)>,
+}
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = vec![];
+        
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            let error = |what: &str| format!("answers.txt:{}: {what} in `{line}`", i + 1);
+            let mut words = line.splitn(3, ' ');
+            let input = match words.next() {
+                Some("sample") => Input::Sample,
+                Some("input") => Input::Real,
+                _ => return Err(error("expected `sample` or `input`")),
+            };
+            let part = match words.next().and_then(|part| part.parse().ok()) {
+                Some(part @ 1..=2) => part,
+                _ => return Err(error("expected part 1 or 2")),
+            };
+            let expected = match words.next().map(str::trim) {
+                Some("pending") => Expected::Pending,
+                Some(answer) if !answer.is_empty() => Expected::Answer(answer.to_string()),
+                _ => return Err(error("expected an answer")),
+            };
+            
+            if entries.iter().any(|&(other, other_part, _)| other == input && other_part == part) {
+                return Err(error("duplicate answer"));
+            }
+            entries.push((input, part, expected));
+        }
+        
+        Ok(Self { entries })
+    }
+    
+    pub fn get(&self, input: Input, part: u8) -> Option<&Expected> {
+        self.entries.iter()
+            .find(|&&(other, other_part, _)| other == input && other_part == part)
+            .map(|(_, _, expected)| expected)
+    }
+}
+
+/// The result of running one part against its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    /// No answer has been recorded for this part and input.
+    Unrecorded,
+    /// The part is a placeholder, and is recorded as one.
+    Pending,
+    Fail { expected: String, actual: String },
+    Error(ParseError),
+}
+impl Outcome {
+    /// Whether this outcome should fail a test run.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Fail { .. } | Outcome::Error(_))
+    }
+}
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "ok"),
+            Outcome::Unrecorded => write!(f, "no recorded answer"),
+            Outcome::Pending => write!(f, "pending"),
+            Outcome::Fail { expected, actual } => write!(f, "wrong answer\n- {expected}\n+ {actual}"),
+            Outcome::Error(e) => write!(f, "error: {e}"),
+        }
+    }
+}
+
+/// Runs `part` of `day` on `text`, and compares it against the answer recorded for `input`.
+pub fn check(day: &Day, input: Input, part: u8, text: &str) -> Result<Outcome, String> {
+    let answers = Answers::parse(day.answers).map_err(|e| format!("day{:02}/{e}", day.number))?;
+    if !(1..=2).contains(&part) {
+        return Err(format!("day {} has no part {part}", day.number));
+    }
+    
+    let expected = match answers.get(input, part) {
+        Some(expected) => expected,
+        None => return Ok(Outcome::Unrecorded),
+    };
+    let actual = match day.solve(text, part) {
+        Ok(actual) => actual,
+        Err(e) => return Ok(Outcome::Error(e)),
+    };
+    
+    // a placeholder that's been solved should have its answer recorded, so that's a failure too
+    let expected = match expected {
+        Expected::Pending if !actual.is_solved() => return Ok(Outcome::Pending),
+        Expected::Pending => "pending".to_string(),
+        Expected::Answer(expected) => expected.clone(),
+    };
+    let actual = actual.to_string();
+    
+    Ok(match expected == actual {
+        true => Outcome::Pass,
+        false => Outcome::Fail { expected, actual },
+    })
+}
\ No newline at end of file
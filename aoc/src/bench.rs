@@ -0,0 +1,200 @@
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::hint::black_box;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use common::error::ParseError;
+use crate::days::Day;
+use crate::memory::{self, Allocations};
+
+/// Summary of repeated timings of the same thing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+impl Stats {
+    /// Panics if there are no `samples`.
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / sorted.len() as f64;
+        let variance = sorted.iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>() / sorted.len() as f64;
+        
+        Self {
+            median,
+            min: sorted[0],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// The part of a day being timed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    /// Solving a part, not counting the parse that comes before it.
+    Solve(u8),
+}
+impl Phase {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Solve(1)),
+            "part2" => Some(Phase::Solve(2)),
+            _ => None,
+        }
+    }
+}
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+    /// What one run allocated, if allocations were being counted. This isn't kept in the history.
+    pub allocations: Option<Allocations>,
+}
+
+/// Times `runs` calls to `f`, after one untimed warm-up call.
+pub fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    black_box(f());
+    
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Times parsing `input`, then solving each of `parts` from the already parsed input.
+pub fn bench(day: &Day, input: &str, parts: &[u8], runs: usize) -> Result<Vec<Measurement>, ParseError> {
+    let (parsed, allocations) = memory::measure(|| (day.parse)(input));
+    let parsed = parsed?;
+    let parse = Stats::new(&time(runs, || (day.parse)(input)));
+    
+    let mut measurements = vec![Measurement { day: day.number, phase: Phase::Parse, stats: parse, allocations }];
+    for &part in parts {
+        let (result, allocations) = memory::measure(|| parsed.solve(part));
+        result?;
+        
+        let stats = Stats::new(&time(runs, || parsed.solve(part)));
+        measurements.push(Measurement { day: day.number, phase: Phase::Solve(part), stats, allocations });
+    }
+    
+    Ok(measurements)
+}
+
+/// A measurement saved by an earlier run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// When the run started, in seconds since the unix epoch.
+    pub run: u64,
+    pub measurement: Measurement,
+}
+
+/// Reads the history file at `path`, which is empty if it doesn't exist yet.
+///
+/// Each line is a tab separated `run day phase median min stddev`, with the times in nanoseconds.
+pub fn load_history(path: &Path) -> Result<Vec<Record>, String> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("failed to read {}: {e}", path.display())),
+    };
+    
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| parse_record(line).ok_or_else(|| format!("{}:{}: malformed record `{line}`", path.display(), i + 1)))
+        .collect()
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let fields = line.split('\t').collect::<Vec<_>>();
+    let [run, day, phase, median, min, stddev] = fields[..] else {
+        return None;
+    };
+    let nanos = |field: &str| field.parse().ok().map(Duration::from_nanos);
+    
+    Some(Record {
+        run: run.parse().ok()?,
+        measurement: Measurement {
+            day: day.parse().ok()?,
+            phase: Phase::from_name(phase)?,
+            stats: Stats {
+                median: nanos(median)?,
+                min: nanos(min)?,
+                stddev: nanos(stddev)?,
+            },
+            allocations: None,
+        },
+    })
+}
+
+/// Appends `measurements` to the history file at `path`, creating it if needed.
+pub fn save_history(path: &Path, run: u64, measurements: &[Measurement]) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("failed to write {}: {e}", path.display());
+    let new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(error)?;
+    
+    if new {
+        writeln!(file, "# run\tday\tphase\tmedian_ns\tmin_ns\tstddev_ns").map_err(error)?;
+    }
+    for Measurement { day, phase, stats, .. } in measurements {
+        writeln!(file, "{run}\t{day}\t{phase}\t{}\t{}\t{}", stats.median.as_nanos(), stats.min.as_nanos(), stats.stddev.as_nanos()).map_err(error)?;
+    }
+    
+    Ok(())
+}
+
+/// The most recent recorded measurement of the same day and phase.
+pub fn previous<'a>(history: &'a [Record], measurement: &Measurement) -> Option<&'a Record> {
+    history.iter()
+        .filter(|record| record.measurement.day == measurement.day && record.measurement.phase == measurement.phase)
+        .max_by_key(|record| record.run)
+}
+
+/// How much slower `current` is than `previous`, as a percentage of the previous median.
+pub fn change(previous: &Stats, current: &Stats) -> f64 {
+    let previous = previous.median.as_secs_f64();
+    if previous == 0.0 {
+        return 0.0;
+    }
+    
+    (current.median.as_secs_f64() - previous) / previous * 100.0
+}
+
+/// Formats `duration` with a unit that suits its size.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", duration.as_secs_f64()),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the puzzle site, from `aoc.conf` and the environment.
+///
+/// The file holds `key = value` lines, with `#` starting a comment. `AOC_SESSION` and `AOC_BASE_URL`
+/// override the `session` and `base_url` keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The value of the site's `session` cookie.
+    pub session: Option<String>,
+    pub base_url: String,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+impl Config {
+    /// `aoc.conf` at the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../aoc.conf")
+    }
+    
+    /// Reads the file at `path` if there is one, then applies the environment on top of it.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let config = match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}:{e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(format!("failed to read {}: {e}", path.display())),
+        };
+        
+        Ok(config.with_env(|name| std::env::var(name).ok()))
+    }
+    
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            let (key, value) = line.split_once('=').ok_or_else(|| format!("{}: expected `key = value`, found `{line}`", i + 1))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                key => return Err(format!("{}: unknown key `{key}`", i + 1)),
+            }
+        }
+        
+        Ok(config)
+    }
+    
+    /// Overrides settings with any of the `AOC_*` variables that `var` finds.
+    pub fn with_env(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(session) = var("AOC_SESSION").filter(|session| !session.is_empty()) {
+            self.session = Some(session);
+        }
+        if let Some(base_url) = var("AOC_BASE_URL").filter(|base_url| !base_url.is_empty()) {
+            self.base_url = base_url;
+        }
+        
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,167 @@
+use std::path::{Path, PathBuf};
+use common::answer::{Answer, Completion};
+use common::error::{Line, ParseError};
+use common::solution::Solution;
+
+/// A day's input once parsed, ready to solve either part of.
+pub trait Parsed {
+    /// Panics if `part` isn't 1 or 2.
+    fn solve(&self, part: u8) -> Result<Answer, ParseError>;
+    
+    /// Solves `part` with each of its variants, by name.
+    fn variants(&self, part: u8) -> Vec<(&'static str, Result<Answer, ParseError>)>;
+    
+    /// See [`Solution::render`].
+    fn render(&self, colour: bool) -> Option<String>;
+}
+
+struct Prepared<'a, S: Solution>(S::Input<'a>);
+impl<S: Solution> Parsed for Prepared<'_, S> {
+    fn solve(&self, part: u8) -> Result<Answer, ParseError> {
+        match part {
+            1 => S::part1(&self.0),
+            2 => S::part2(&self.0),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+    
+    fn variants(&self, part: u8) -> Vec<(&'static str, Result<Answer, ParseError>)> {
+        S::variants().into_iter()
+            .filter(|variant| variant.part == part)
+            .map(|variant| (variant.name, (variant.solve)(&self.0)))
+            .collect()
+    }
+    
+    fn render(&self, colour: bool) -> Option<String> {
+        S::render(&self.0, colour)
+    }
+}
+
+/// Day 1's input, solved with the rules from its options instead of the defaults.
+struct Configured<'a> {
+    lines: Vec<Line<'a>>,
+    configs: [day01::Config; 2],
+}
+impl Parsed for Configured<'_> {
+    fn solve(&self, part: u8) -> Result<Answer, ParseError> {
+        match part {
+            1 | 2 => day01::calibration_sum(&self.lines, &self.configs[part as usize - 1]),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+    
+    /// The variants don't take options, so there are none to compare against.
+    fn variants(&self, _part: u8) -> Vec<(&'static str, Result<Answer, ParseError>)> {
+        vec![]
+    }
+    
+    fn render(&self, _colour: bool) -> Option<String> {
+        None
+    }
+}
+
+/// Day 1's input, parsed for solving with `configs` rather than the defaults, see [`day01::configs`].
+pub fn prepare_day01(input: &str, configs: [day01::Config; 2]) -> Result<Box<dyn Parsed + '_>, ParseError> {
+    Ok(Box::new(Configured {
+        lines: day01::Day01::parse(input)?,
+        configs,
+    }))
+}
+
+pub type Parser = for<'a> fn(&'a str) -> Result<Box<dyn Parsed + 'a>, ParseError>;
+
+/// The [`Parser`] for `S`.
+pub fn prepare<'a, S: Solution + 'static>(input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+    Ok(Box::new(Prepared::<S>(S::parse(input)?)))
+}
+
+/// The name and part of each of `S`'s variants.
+pub fn variant_names<S: Solution>() -> Vec<(&'static str, u8)> {
+    S::variants().iter().map(|variant| (variant.name, variant.part)).collect()
+}
+
+/// A solved day, and the entry points the runner needs to drive it.
+#[derive(Debug, Copy, Clone)]
+pub struct Day {
+    pub number: u8,
+    pub sample: &'static str,
+    pub input_path: &'static str,
+    /// The day's `answers.txt`, see [`crate::answers::Answers`].
+    pub answers: &'static str,
+    pub parse: Parser,
+    /// Makes a random input of roughly `size` from a seed, see each day's `generator` module.
+    pub generate: fn(u64, usize) -> String,
+    /// See [`variant_names`].
+    pub variants: fn() -> Vec<(&'static str, u8)>,
+}
+impl Day {
+    /// Parses `input` and solves `part` of it.
+    pub fn solve(&self, input: &str, part: u8) -> Result<Answer, ParseError> {
+        (self.parse)(input)?.solve(part)
+    }
+    
+    /// Which parts are solved, going by what they return for the sample.
+    ///
+    /// A part that fails on the sample still counts as solved, since it isn't a placeholder.
+    pub fn completion(&self) -> Completion {
+        let solved = |part| self.solve(self.sample, part).map_or(true, |answer| answer.is_solved());
+        
+        Completion::new(solved(1), solved(2))
+    }
+}
+
+macro_rules! day {
+    ($number:literal, $krate:ident::$solution:ident) => {
+        Day {
+            number: $number,
+            sample: $krate::SAMPLE,
+            input_path: $krate::INPUT_PATH,
+            answers: $krate::ANSWERS,
+            parse: prepare::<$krate::$solution>,
+            generate: $krate::generator::generate,
+            variants: variant_names::<$krate::$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, day01::Day01),
+    day!(2, day02::Day02),
+    day!(3, day03::Day03),
+    day!(4, day04::Day04),
+    day!(5, day05::Day05),
+    day!(6, day06::Day06),
+    day!(7, day07::Day07),
+    day!(8, day08::Day08),
+    day!(9, day09::Day09),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11),
+    day!(13, day13::Day13),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// The root of the workspace the runner was built in.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+/// The crate for day `number`, whether or not it's been added to [`DAYS`] yet.
+pub fn crate_dir(number: u8) -> PathBuf {
+    workspace_dir().join(format!("day{number:02}"))
+}
+
+/// Where day `number` keeps its [`crate::ledger::Ledger`] of submitted answers.
+pub fn ledger_path(number: u8) -> PathBuf {
+    crate_dir(number).join("src/ledger.txt")
+}
+
+/// Where day `number` keeps its input.
+pub fn input_path(number: u8) -> PathBuf {
+    match find(number) {
+        Some(day) => day.input_path.into(),
+        None => crate_dir(number).join("src/input.txt"),
+    }
+}
\ No newline at end of file
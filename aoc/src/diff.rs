@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+use common::answer::Answer;
+use common::error::ParseError;
+use crate::days::Day;
+
+/// A variant that got something different from the main solution of its part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: u8,
+    pub variant: &'static str,
+    /// What the main solution got.
+    pub expected: String,
+    pub actual: String,
+}
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part{} `{}` disagrees\n- {}\n+ {}", self.part, self.variant, self.expected, self.actual)
+    }
+}
+
+fn describe(result: &Result<Answer, ParseError>) -> String {
+    match result {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Runs every variant of `day` on `input`, returning the first that disagrees with its part.
+///
+/// An input that doesn't parse has nothing to disagree about.
+pub fn compare(day: &Day, input: &str) -> Option<Disagreement> {
+    let parsed = (day.parse)(input).ok()?;
+    
+    for part in 1..=2 {
+        let variants = parsed.variants(part);
+        if variants.is_empty() {
+            continue;
+        }
+        
+        let expected = describe(&parsed.solve(part));
+        for (variant, result) in variants {
+            let actual = describe(&result);
+            if actual != expected {
+                return Some(Disagreement { part, variant, expected, actual });
+            }
+        }
+    }
+    
+    None
+}
+
+/// Shrinks `input` for as long as `fails` still holds, first by whole lines, then by the words in them.
+pub fn minimize(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let join = |lines: &[String]| lines.iter().map(|line| format!("{line}\n")).collect::<String>();
+    let mut lines = input.lines().map(str::to_string).collect::<Vec<_>>();
+    
+    // removing chunks of lines, and only trying smaller chunks once none can be removed
+    let mut chunk = lines.len().div_ceil(2);
+    while chunk > 0 {
+        let mut removed = false;
+        let mut start = 0;
+        while start < lines.len() {
+            let end = (start + chunk).min(lines.len());
+            let candidate = [&lines[..start], &lines[end..]].concat();
+            if !candidate.is_empty() && fails(&join(&candidate)) {
+                lines = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+        
+        if !removed {
+            chunk /= 2;
+        }
+    }
+    
+    for i in 0..lines.len() {
+        let mut word = 0;
+        loop {
+            let mut words = lines[i].split(' ').collect::<Vec<_>>();
+            if word >= words.len() {
+                break;
+            }
+            words.remove(word);
+            
+            let mut candidate = lines.clone();
+            candidate[i] = words.join(" ");
+            if fails(&join(&candidate)) {
+                lines = candidate;
+            } else {
+                word += 1;
+            }
+        }
+    }
+    
+    join(&lines)
+}
+
+/// A generated input that a variant disagrees on, after it's been minimized.
+#[derive(Debug, Clone)]
+pub struct Counterexample {
+    pub seed: u64,
+    pub size: usize,
+    /// How the minimized input is disagreed on.
+    pub disagreement: Disagreement,
+    pub input: String,
+}
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.disagreement)?;
+        writeln!(f, "found with seed {} and size {}, minimized to:", self.seed, self.size)?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Compares `day`'s variants on inputs generated from each of `seeds` at each size up to
+/// `max_size`, smallest first, stopping at the first disagreement.
+pub fn search(day: &Day, seeds: u64, max_size: usize) -> Option<Counterexample> {
+    for size in 1..=max_size {
+        for seed in 0..seeds {
+            let input = (day.generate)(seed, size);
+            let Some(found) = compare(day, &input) else {
+                continue;
+            };
+            
+            let same = |text: &str| compare(day, text).is_some_and(|other| other.part == found.part && other.variant == found.variant);
+            let input = minimize(&input, same);
+            
+            return Some(Counterexample {
+                seed,
+                size,
+                disagreement: compare(day, &input).unwrap_or(found),
+                input,
+            });
+        }
+    }
+    
+    None
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use std::path::Path;
+use std::time::Duration;
+use crate::ledger::Verdict;
+
+/// Talks to the puzzle site at `base_url`, logged in with a session cookie.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!("aoc-2023/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+    
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let url = format!("{}/2023/day/{day}/input", self.base_url);
+        let response = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        
+        match response {
+            Ok(response) => response.into_string().map_err(|e| format!("failed to read the response from {url}: {e}")),
+            Err(ureq::Error::Status(404, _)) => Err(format!("day {day}'s input isn't available yet")),
+            Err(ureq::Error::Status(400, _)) => Err("the session token was rejected".to_string()),
+            Err(ureq::Error::Status(status, _)) => Err(format!("{url} returned status {status}")),
+            Err(e) => Err(format!("failed to fetch {url}: {e}")),
+        }
+    }
+    
+    /// Submits `answer` for `part`, and reads the verdict out of the page that comes back.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        let url = format!("{}/2023/day/{day}/answer", self.base_url);
+        let response = self.agent.post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        
+        let page = match response {
+            Ok(response) => response.into_string().map_err(|e| format!("failed to read the response from {url}: {e}"))?,
+            Err(ureq::Error::Status(status, _)) => return Err(format!("{url} returned status {status}")),
+            Err(e) => return Err(format!("failed to submit to {url}: {e}")),
+        };
+        
+        if page.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if page.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Ok(Verdict::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            Err("answered too recently, wait a bit before trying again".to_string())
+        } else if page.contains("Did you already complete it") {
+            Err(format!("part {part} is already complete, or not unlocked yet"))
+        } else {
+            Err("couldn't find a verdict in the response".to_string())
+        }
+    }
+}
+
+/// Downloads `day`'s input to `path`, unless it's already there.
+///
+/// Returns whether anything was downloaded. The file only appears once it's complete, so an
+/// interrupted download is never mistaken for a cached one.
+pub fn fetch(client: &Client, day: u8, path: &Path) -> Result<bool, String> {
+    if path.exists() {
+        return Ok(false);
+    }
+    
+    let input = client.input(day)?;
+    if input.is_empty() {
+        return Err(format!("day {day}'s input came back empty"));
+    }
+    
+    let partial = path.with_extension("part");
+    std::fs::write(&partial, input).map_err(|e| format!("failed to write {}: {e}", partial.display()))?;
+    std::fs::rename(&partial, path).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+    
+    Ok(true)
+}
\ No newline at end of file
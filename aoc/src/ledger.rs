@@ -0,0 +1,169 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use crate::fetch::Client;
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+}
+impl Verdict {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.pad("correct"),
+            Verdict::TooHigh => f.pad("too-high"),
+            Verdict::TooLow => f.pad("too-low"),
+            Verdict::Wrong => f.pad("wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a day, and what the site said about it.
+///
+/// Stored as one `<part> <answer> <verdict>` line per submission, with `#` starting a comment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub entries: Vec<Entry>,
+}
+impl Ledger {
+    /// Reads the ledger at `path`, which is empty if it doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}:{e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+    
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = vec![];
+        
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let entry = match words[..] {
+                [part @ ("1" | "2"), answer, verdict] => Verdict::from_name(verdict).map(|verdict| Entry {
+                    part: if part == "1" { 1 } else { 2 },
+                    answer: answer.to_string(),
+                    verdict,
+                }),
+                _ => None,
+            };
+            entries.push(entry.ok_or_else(|| format!("{}: expected `<part> <answer> <verdict>`, found `{line}`", i + 1))?);
+        }
+        
+        Ok(Self { entries })
+    }
+    
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut text = String::from("# part answer verdict\n");
+        for Entry { part, answer, verdict } in &self.entries {
+            text += &format!("{part} {answer} {verdict}\n");
+        }
+        
+        std::fs::write(path, text).map_err(|e| format!("failed to write {}: {e}", path.display()))
+    }
+    
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        self.entries.push(Entry {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+    
+    /// The range a numeric answer to `part` must fall in, going by the too high and too low verdicts.
+    ///
+    /// Both ends are exclusive, and `None` where nothing is known yet.
+    pub fn bounds(&self, part: u8) -> (Option<i64>, Option<i64>) {
+        let numbers = |verdict| self.entries.iter()
+            .filter(move |entry| entry.part == part && entry.verdict == verdict)
+            .filter_map(|entry| entry.answer.parse::<i64>().ok());
+        
+        (numbers(Verdict::TooLow).max(), numbers(Verdict::TooHigh).min())
+    }
+    
+    /// Checks that `answer` is worth submitting for `part`.
+    pub fn vet(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        let entries = self.entries.iter().filter(|entry| entry.part == part);
+        for entry in entries {
+            match entry.verdict {
+                Verdict::Correct if entry.answer == answer => return Err(Refusal::AlreadyCorrect),
+                Verdict::Correct => return Err(Refusal::KnownAnswer(entry.answer.clone())),
+                verdict if entry.answer == answer => return Err(Refusal::KnownWrong(verdict)),
+                _ => {},
+            }
+        }
+        
+        let Ok(value) = answer.parse::<i64>() else {
+            return Ok(());
+        };
+        match self.bounds(part) {
+            (Some(low), _) if value <= low => Err(Refusal::TooLow(low)),
+            (_, Some(high)) if value >= high => Err(Refusal::TooHigh(high)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Why an answer wasn't submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect,
+    /// The part has already been solved, with this answer.
+    KnownAnswer(String),
+    KnownWrong(Verdict),
+    /// At or below an answer that was too low.
+    TooLow(i64),
+    /// At or above an answer that was too high.
+    TooHigh(i64),
+}
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect => write!(f, "this answer was already accepted"),
+            Refusal::KnownAnswer(answer) => write!(f, "this part was already solved with {answer}"),
+            Refusal::KnownWrong(verdict) => write!(f, "this answer was already submitted, and was {verdict}"),
+            Refusal::TooLow(low) => write!(f, "{low} was already too low"),
+            Refusal::TooHigh(high) => write!(f, "{high} was already too high"),
+        }
+    }
+}
+
+/// Submits `answer` for `part` of `day`, unless the ledger at `path` rules it out, then records the verdict.
+pub fn submit(client: &Client, path: &Path, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+    let mut ledger = Ledger::load(path)?;
+    ledger.vet(part, answer).map_err(|refusal| format!("refusing to submit {answer}: {refusal}"))?;
+    
+    let verdict = client.submit(day, part, answer)?;
+    ledger.record(part, answer, verdict);
+    ledger.save(path)?;
+    
+    Ok(verdict)
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod days;
+pub mod diff;
+pub mod fetch;
+pub mod ledger;
+pub mod memory;
+pub mod pool;
+pub mod report;
+pub mod scaffold;
+pub mod watch;
\ No newline at end of file
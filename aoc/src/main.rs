@@ -0,0 +1,635 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime};
+use std::str::FromStr;
+use aoc::answers::{self, Input};
+use aoc::bench::{self, Measurement};
+use aoc::config::Config;
+use aoc::days::{self, Day};
+use aoc::diff;
+use aoc::fetch::{self, Client};
+use aoc::ledger::{self, Ledger};
+use aoc::memory::{self, Allocations};
+use aoc::pool;
+use aoc::report::{self, Row};
+use aoc::scaffold;
+use aoc::watch;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::input::Source;
+use common::solution::{self, Solution};
+use day01::Day01;
+use common::trace::{self, Level};
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show what the solutions are doing on stderr, repeated for more detail
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Show everything from TARGET, like `day10`, regardless of -v
+    #[arg(long, value_name = "TARGET", global = true)]
+    trace: Vec<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Runs a single day, or `all` of them
+    Run {
+        /// Day number, or `all`
+        day: Selection,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Use the day's bundled sample instead of its input.txt
+        #[arg(long, conflicts_with = "input")]
+        sample: bool,
+        /// Read the input from PATH instead, or `-` for stdin
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// How to print each part's result
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Draw the input after the answers, for the days that can
+        #[arg(long)]
+        render: bool,
+        /// Run `all` the days on N threads, then print a summary of them
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "render")]
+        jobs: Option<u64>,
+        #[command(flatten)]
+        day01: Day01Options,
+    },
+    /// Checks a day, or `all` of them, against their recorded answers
+    Check {
+        /// Day number, or `all`
+        day: Selection,
+        /// Only check against the bundled samples
+        #[arg(long)]
+        sample: bool,
+    },
+    /// Times a day, or `all` of them, comparing against earlier runs
+    Bench {
+        /// Day number, or `all`
+        day: Selection,
+        /// Only time this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Use the bundled samples instead of the real inputs
+        #[arg(long)]
+        sample: bool,
+        /// How many timed runs of each part
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Where results are saved and compared against
+        #[arg(long, value_name = "PATH", default_value = "bench-history.tsv")]
+        history: PathBuf,
+        /// Flag a part as regressed when its median is this many percent slower
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Compare against the history without adding this run to it
+        #[arg(long)]
+        no_save: bool,
+    },
+    /// Checks that every variant of a day's parts, or of `all` days, agrees with the main solution
+    Diff {
+        /// Day number, or `all`
+        day: Selection,
+        /// How many seeds to generate inputs from, at each size
+        #[arg(long, default_value_t = 20)]
+        seeds: u64,
+        /// The largest size of input to generate
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+    },
+    /// Downloads a day's input, unless it's already been downloaded
+    Fetch {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Fetch from this site instead of the configured one
+        #[arg(long, value_name = "URL")]
+        base_url: Option<String>,
+        /// Read settings from PATH instead of the workspace's aoc.conf
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+    },
+    /// Submits an answer, unless the day's ledger already rules it out
+    Submit {
+        /// Day number
+        day: Selection,
+        /// Which part the answer is for
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The answer to submit, instead of solving the day's input for it
+        answer: Option<String>,
+        /// Submit to this site instead of the configured one
+        #[arg(long, value_name = "URL")]
+        base_url: Option<String>,
+        /// Read settings from PATH instead of the workspace's aoc.conf
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+    },
+    /// Shows the answers submitted for a day, and the bounds they put on each part
+    Ledger {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Prints a random input for a day, the same one every time for the same seed and size
+    Gen {
+        /// Day number
+        day: Selection,
+        /// Seed for the random numbers
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how big an input to make, like a number of lines or the width of a grid
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+    },
+    /// Runs a day, then again whenever its source or input changes, comparing answers with the last run
+    Watch {
+        /// Day number
+        day: Selection,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Use the day's bundled sample instead of its input.txt
+        #[arg(long, conflicts_with = "input")]
+        sample: bool,
+        /// Read the input from PATH instead
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// How often to check for changes, in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = 500)]
+        interval: u64,
+    },
+    /// Starts a crate for a new day and adds it to the runner, without touching an existing one
+    New {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Lists the available days
+    List,
+}
+
+/// Day 1's own options, the same as its binary takes.
+#[derive(Debug, Args)]
+struct Day01Options {
+    /// Day 1: read part 2's number words from PATH instead of using English
+    #[arg(long, value_name = "PATH")]
+    vocabulary: Option<String>,
+    /// Day 1: number words for part 2, like `un=1,deux=2`, on top of any --vocabulary
+    #[arg(long, value_name = "WORDS")]
+    words: Option<String>,
+    /// Day 1: which digits count, `ascii` or `unicode`
+    #[arg(long, value_name = "MODE")]
+    digits: Option<String>,
+    /// Day 1: show how part 2 reads each line instead of the answers
+    #[arg(long, conflicts_with = "render")]
+    explain: bool,
+}
+impl Day01Options {
+    fn is_set(&self) -> bool {
+        self.vocabulary.is_some() || self.words.is_some() || self.digits.is_some() || self.explain
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One `dayNN partN: answer` line per part
+    Text,
+    /// One JSON object per line per part, with timings
+    Json,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Selection {
+    All,
+    Day(u8),
+}
+impl FromStr for Selection {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+        
+        let number = s.parse::<u8>().map_err(|_| format!("expected a day number or `all`, found `{s}`"))?;
+        match days::find(number) {
+            Some(_) => Ok(Self::Day(number)),
+            None => Err(format!("day {number} is not solved")),
+        }
+    }
+}
+
+/// Solves each of `parts`, printing a record for each, and returning the last error if any failed.
+///
+/// With `render`, the input is drawn after the records, if the day can draw it.
+fn run(day: &Day, parts: &[u8], source: &Source, format: Format, render: bool, day01: &Day01Options) -> Result<(), String> {
+    let input = source.read(day.sample)?;
+    let (parsed, records) = match day01.is_set() {
+        false => report::solve(day, &input, parts),
+        true => {
+            let configs = day01::configs(day01.vocabulary.as_deref(), day01.words.as_deref(), day01.digits.as_deref())?;
+            if day01.explain {
+                return explain_day01(&input, &configs[1]);
+            }
+            
+            report::solve_with(day.number, || days::prepare_day01(&input, configs), parts)
+        },
+    };
+    
+    let mut error = None;
+    for record in records {
+        match format {
+            Format::Text if record.result.is_err() => eprintln!("{record}"),
+            Format::Text => println!("{record}"),
+            Format::Json => println!("{}", record.to_json()),
+        }
+        if let Err(e) = record.result {
+            error = Some(e.to_string());
+        }
+    }
+    
+    if let (true, Ok(parsed)) = (render, &parsed) {
+        match parsed.render(solution::colour()) {
+            Some(drawing) => print!("{drawing}"),
+            None => eprintln!("day{:02}: nothing to render", day.number),
+        }
+    }
+    
+    match error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Prints how day 1's part 2 reads each line under `config`, failing if any line has no value.
+fn explain_day01(input: &str, config: &day01::Config) -> Result<(), String> {
+    let lines = Day01::parse(input).map_err(|e| e.to_string())?;
+    let (report, invalid) = day01::explain(&lines, config);
+    print!("{report}");
+    
+    match invalid {
+        0 => Ok(()),
+        n => Err(format!("{n} line(s) have no calibration value")),
+    }
+}
+
+/// Runs every day on `jobs` threads, returning their rows in day order.
+fn run_all(parts: &[u8], sample: bool, jobs: usize) -> Vec<Row> {
+    pool::map(days::DAYS, jobs, |day| {
+        let start = Instant::now();
+        let source = if sample { Source::Sample } else { Source::File(day.input_path.into()) };
+        let records = source.read(day.sample).map(|input| report::solve(day, &input, parts).1);
+        
+        Row {
+            day: day.number,
+            records,
+            time: start.elapsed(),
+        }
+    })
+}
+
+/// Prints every part's outcome, returning an error if any of them failed.
+fn check(day: &Day, inputs: &[Input]) -> Result<(), String> {
+    let mut failed = false;
+    
+    for &input in inputs {
+        let text = match input {
+            Input::Sample => day.sample.to_string(),
+            Input::Real => match std::fs::read_to_string(day.input_path) {
+                Ok(text) => text,
+                Err(_) => continue,
+            },
+        };
+        
+        for part in 1..=2 {
+            let outcome = answers::check(day, input, part, &text)?;
+            println!("day{:02} {} part{part}: {outcome}", day.number, input.name());
+            failed |= outcome.is_failure();
+        }
+    }
+    
+    match failed {
+        true => Err(format!("day {} has wrong answers", day.number)),
+        false => Ok(()),
+    }
+}
+
+struct BenchOptions {
+    part: Option<u8>,
+    sample: bool,
+    runs: usize,
+    history: PathBuf,
+    threshold: f64,
+    save: bool,
+}
+
+/// Times each day, printing a row per phase as it goes, then saves the results.
+fn bench_days(days: &[Day], options: &BenchOptions) -> Result<(), String> {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: this is a debug build, timings will be much slower than with --release");
+    }
+    
+    let history = bench::load_history(&options.history)?;
+    let run = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0);
+    
+    let counting = memory::counting();
+    match counting {
+        true => println!("{:<6} {:<6} {:>10} {:>10} {:>10} {:>8} {:>10} {:>10}  vs previous", "day", "phase", "median", "min", "stddev", "allocs", "bytes", "peak heap"),
+        false => println!("{:<6} {:<6} {:>10} {:>10} {:>10}  vs previous", "day", "phase", "median", "min", "stddev"),
+    }
+    let mut measurements = vec![];
+    let mut regressions = 0;
+    for day in days {
+        let parts = match options.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        };
+        let source = if options.sample { Source::Sample } else { Source::File(day.input_path.into()) };
+        let input = match source.read(day.sample) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day{:02}: skipped, {e}", day.number);
+                continue;
+            },
+        };
+        
+        let results = match bench::bench(day, &input, &parts, options.runs) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("day{:02}: skipped, {e}", day.number);
+                continue;
+            },
+        };
+        for measurement in results {
+            let Measurement { day, phase, stats, allocations } = measurement;
+            let comparison = match bench::previous(&history, &measurement) {
+                Some(previous) => {
+                    let change = bench::change(&previous.measurement.stats, &stats);
+                    if change > options.threshold {
+                        regressions += 1;
+                        format!("{change:+.1}% REGRESSION")
+                    } else {
+                        format!("{change:+.1}%")
+                    }
+                },
+                None => "-".to_string(),
+            };
+            
+            let allocations = match allocations {
+                Some(Allocations { count, bytes, peak_heap }) if counting => {
+                    format!(" {count:>8} {:>10} {:>10}", memory::format_bytes(bytes), memory::format_bytes(peak_heap))
+                },
+                _ => String::new(),
+            };
+            println!(
+                "day{day:02}  {phase:<6} {:>10} {:>10} {:>10}{allocations}  {comparison}",
+                bench::format_duration(stats.median),
+                bench::format_duration(stats.min),
+                bench::format_duration(stats.stddev),
+            );
+            measurements.push(measurement);
+        }
+    }
+    
+    if regressions > 0 {
+        eprintln!("{regressions} phase(s) regressed by more than {}%", options.threshold);
+    }
+    // benchmarking the samples says little about real performance, so those stay out of the history
+    if options.save && !options.sample {
+        bench::save_history(&options.history, run, &measurements)?;
+    }
+    
+    Ok(())
+}
+
+/// A client for the puzzle site, configured from `config` (or the default `aoc.conf`) and the environment.
+fn client(base_url: Option<String>, config: Option<PathBuf>) -> Result<Client, String> {
+    let config = Config::load(&config.unwrap_or_else(Config::default_path))?;
+    let session = config.session.ok_or("no session token, set AOC_SESSION or `session` in aoc.conf")?;
+    
+    Ok(Client::new(base_url.as_deref().unwrap_or(&config.base_url), &session))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    trace::init(Level::from_verbosity(cli.verbose), cli.trace);
+    
+    let result = match cli.command {
+        Command::Run { render: true, format: Format::Json, .. } => Err("--render only works with --format text".to_string()),
+        Command::Run { day: Selection::Day(_), jobs: Some(_), .. } => Err("--jobs only works with `all`".to_string()),
+        Command::Run { day, day01, .. } if day01.is_set() && !matches!(day, Selection::Day(1)) => {
+            Err("--vocabulary, --words, --digits and --explain only work with day 1".to_string())
+        },
+        Command::Run { format: Format::Json, day01: Day01Options { explain: true, .. }, .. } => Err("--explain only works with --format text".to_string()),
+        Command::Run { day, part, sample, input, format, render, jobs, day01 } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            
+            match day {
+                Selection::Day(number) => {
+                    let day = days::find(number).unwrap();
+                    let source = match (sample, input) {
+                        (true, _) => Source::Sample,
+                        (false, Some(path)) if path.as_os_str() == "-" => Source::Stdin,
+                        (false, Some(path)) => Source::File(path),
+                        (false, None) => Source::File(day.input_path.into()),
+                    };
+                    
+                    run(day, &parts, &source, format, render, &day01)
+                },
+                Selection::All if input.is_some() => Err("--input cannot be used with `all`".to_string()),
+                Selection::All => {
+                    let failures = match jobs {
+                        Some(jobs) => {
+                            let rows = run_all(&parts, sample, jobs as usize);
+                            match format {
+                                Format::Text => print!("{}", report::summary(&rows)),
+                                Format::Json => for record in rows.iter().filter_map(|row| row.records.as_ref().ok()).flatten() {
+                                    println!("{}", record.to_json());
+                                },
+                            }
+                            
+                            rows.iter().filter(|row| row.is_failure()).count()
+                        },
+                        None => days::DAYS.iter()
+                            .filter_map(|day| {
+                                let source = if sample { Source::Sample } else { Source::File(day.input_path.into()) };
+                                
+                                run(day, &parts, &source, format, render, &day01).err()
+                            })
+                            .count(),
+                    };
+                    
+                    match failures {
+                        0 => Ok(()),
+                        n => Err(format!("{n} day(s) failed")),
+                    }
+                },
+            }
+        },
+        Command::Check { day, sample } => {
+            let inputs: &[Input] = if sample { &[Input::Sample] } else { &[Input::Sample, Input::Real] };
+            
+            match day {
+                Selection::Day(number) => check(days::find(number).unwrap(), inputs),
+                Selection::All => {
+                    // check every day before reporting, so one failure doesn't hide the rest
+                    let failures = days::DAYS.iter()
+                        .filter_map(|day| check(day, inputs).err())
+                        .collect::<Vec<_>>();
+                    
+                    match failures.is_empty() {
+                        true => Ok(()),
+                        false => Err(failures.join("\n")),
+                    }
+                },
+            }
+        },
+        Command::Bench { day, part, sample, runs, history, threshold, no_save } => {
+            let options = BenchOptions {
+                part,
+                sample,
+                runs: runs as usize,
+                history,
+                threshold,
+                save: !no_save,
+            };
+            
+            match day {
+                Selection::Day(number) => bench_days(std::slice::from_ref(days::find(number).unwrap()), &options),
+                Selection::All => bench_days(days::DAYS, &options),
+            }
+        },
+        Command::Diff { day, seeds, size } => {
+            let selected = match day {
+                Selection::Day(number) => vec![days::find(number).unwrap()],
+                Selection::All => days::DAYS.iter().collect(),
+            };
+            
+            let mut failures = 0;
+            for day in selected.iter().filter(|day| !(day.variants)().is_empty()) {
+                match diff::search(day, seeds, size as usize) {
+                    Some(counterexample) => {
+                        eprintln!("day{:02} {counterexample}", day.number);
+                        failures += 1;
+                    },
+                    None => println!("day{:02}: {} variant(s) agree on {} inputs", day.number, (day.variants)().len(), seeds * size),
+                }
+            }
+            
+            match (failures, day) {
+                (0, Selection::Day(number)) if (selected[0].variants)().is_empty() => Err(format!("day {number} has no variants to compare")),
+                (0, _) => Ok(()),
+                (n, _) => Err(format!("{n} day(s) disagreed")),
+            }
+        },
+        Command::Fetch { day, base_url, config } => client(base_url, config).and_then(|client| {
+            if !days::crate_dir(day).exists() {
+                return Err(format!("there is no day{day:02} crate to save the input into"));
+            }
+            
+            let path = days::input_path(day);
+            match fetch::fetch(&client, day, &path)? {
+                true => println!("day{day:02}: saved to {}", path.display()),
+                false => println!("day{day:02}: already cached at {}", path.display()),
+            }
+            
+            Ok(())
+        }),
+        Command::Submit { day: Selection::All, .. } => Err("answers can only be submitted for one day at a time".to_string()),
+        Command::Submit { day: Selection::Day(number), part, answer, base_url, config } => client(base_url, config).and_then(|client| {
+            let day = days::find(number).unwrap();
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let input = Source::File(day.input_path.into()).read(day.sample)?;
+                    let answer = day.solve(&input, part).map_err(|e| e.to_string())?;
+                    if !answer.is_solved() {
+                        return Err(format!("day {number} part {part} isn't solved yet"));
+                    }
+                    
+                    answer.to_string()
+                },
+            };
+            
+            let verdict = ledger::submit(&client, &days::ledger_path(number), number, part, &answer)?;
+            println!("day{number:02} part{part}: {answer} is {verdict}");
+            
+            Ok(())
+        }),
+        Command::Ledger { day } => Ledger::load(&days::ledger_path(day)).map(|ledger| {
+            for entry in &ledger.entries {
+                println!("part{}  {:<8}  {}", entry.part, entry.verdict, entry.answer);
+            }
+            for part in 1..=2 {
+                match ledger.bounds(part) {
+                    (Some(low), Some(high)) => println!("part{part} is between {low} and {high}"),
+                    (Some(low), None) => println!("part{part} is above {low}"),
+                    (None, Some(high)) => println!("part{part} is below {high}"),
+                    (None, None) => {},
+                }
+            }
+        }),
+        Command::Gen { day: Selection::All, .. } => Err("inputs can only be generated for one day at a time".to_string()),
+        Command::Gen { day: Selection::Day(number), seed, size } => {
+            let day = days::find(number).unwrap();
+            print!("{}", (day.generate)(seed, size as usize));
+            
+            Ok(())
+        },
+        Command::Watch { day: Selection::All, .. } => Err("only one day can be watched at a time".to_string()),
+        Command::Watch { day: Selection::Day(number), part, sample, input, interval } => {
+            let workspace = days::workspace_dir();
+            let mut paths = watch::path_dependencies(&days::crate_dir(number));
+            
+            let mut args = vec![];
+            if let Some(part) = part {
+                args.extend(["--part".to_string(), part.to_string()]);
+            }
+            if sample {
+                args.push("--sample".to_string());
+            }
+            if let Some(input) = input {
+                // the run happens from the workspace, so a relative path has to be made absolute first
+                let input = std::path::absolute(&input).unwrap_or(input);
+                args.extend(["--input".to_string(), input.display().to_string()]);
+                paths.push(input);
+            }
+            
+            watch::watch(&workspace, number, &args, &paths, Duration::from_millis(interval))
+        },
+        Command::New { day } => scaffold::create(&days::workspace_dir(), day).map(|paths| {
+            for path in paths {
+                println!("wrote {}", path.display());
+            }
+            println!("day{day:02}: add the puzzle's example to src/sample.txt and its answers to src/answers.txt and tests/day{day:02}.rs");
+        }),
+        Command::List => {
+            for day in days::DAYS {
+                println!("day{:02}  {:<8}  {}", day.number, day.completion(), day.input_path);
+            }
+            
+            Ok(())
+        },
+    };
+    
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+//! An opt-in global allocator that counts what each thread allocates, for `--features count-allocs`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+
+#[derive(Debug, Copy, Clone)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    /// Bytes currently allocated. Memory freed by a different thread than allocated it is taken off
+    /// the freeing thread, so this can go below zero.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const { Cell::new(Counts { allocations: 0, bytes: 0, live: 0, peak: 0 }) };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // a thread's locals can already be gone while it's shutting down, and then there's nothing to count into
+    let _ = COUNTS.try_with(|counts| {
+        let mut c = counts.get();
+        if allocated > 0 {
+            c.allocations += 1;
+            c.bytes += allocated as u64;
+        }
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counts.set(c);
+    });
+}
+
+/// The system allocator, counting allocations as they're made.
+///
+/// A reallocation counts as one allocation of its new size.
+pub struct Counting;
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        
+        System.alloc(layout)
+    }
+    
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        
+        System.alloc_zeroed(layout)
+    }
+    
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(0, layout.size());
+        
+        System.dealloc(ptr, layout)
+    }
+    
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size, layout.size());
+        
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Whether [`Counting`] is the global allocator, so there's anything to measure.
+///
+/// Found out by making an allocation and seeing whether it was counted.
+pub fn counting() -> bool {
+    let allocations = || COUNTS.with(|counts| counts.get().allocations);
+    
+    let before = allocations();
+    drop(black_box(Box::new(0u8)));
+    allocations() != before
+}
+
+/// What was allocated while something ran.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    /// The most heap memory that was allocated at once, on top of what already was beforehand.
+    ///
+    /// Only what went through the allocator is counted, so this isn't the process's resident memory.
+    pub peak_heap: u64,
+}
+impl Display for Allocations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} allocs, {} total, {} peak heap", self.count, format_bytes(self.bytes), format_bytes(self.peak_heap))
+    }
+}
+
+/// Runs `f`, counting what it allocates on this thread, if [`counting`].
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !counting() {
+        return (f(), None);
+    }
+    
+    let before = COUNTS.with(|counts| {
+        let before = counts.get();
+        counts.set(Counts { peak: before.live, ..before });
+        before
+    });
+    let result = f();
+    let after = COUNTS.with(|counts| {
+        let after = counts.get();
+        counts.set(Counts { peak: after.peak.max(before.peak), ..after });
+        after
+    });
+    
+    (result, Some(Allocations {
+        count: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak_heap: (after.peak - before.live).max(0) as u64,
+    }))
+}
+
+/// Formats `bytes` with a binary unit that suits its size.
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{bytes}B"),
+        1024..=1_048_575 => format!("{:.1}KiB", bytes as f64 / 1024.0),
+        1_048_576..=1_073_741_823 => format!("{:.1}MiB", bytes as f64 / 1_048_576.0),
+        _ => format!("{:.2}GiB", bytes as f64 / 1_073_741_824.0),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+//! A small thread pool for running days side by side.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Calls `f` on every item with up to `jobs` threads, returning the results in the items' order.
+///
+/// Each thread takes the next unstarted item as soon as it's free, so one slow item doesn't hold up
+/// the others queued behind it.
+pub fn map<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    
+    let mut results = std::thread::scope(|scope| {
+        let workers = (0..jobs.clamp(1, items.len().max(1)))
+            .map(|_| scope.spawn(|| {
+                let mut done = vec![];
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        return done;
+                    };
+                    
+                    done.push((i, f(item)));
+                }
+            }))
+            .collect::<Vec<_>>();
+        
+        workers.into_iter()
+            .flat_map(|worker| worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect::<Vec<_>>()
+    });
+    results.sort_by_key(|&(i, _)| i);
+    
+    results.into_iter().map(|(_, result)| result).collect()
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+use common::answer::Answer;
+use common::error::ParseError;
+use serde_json::{json, Value};
+use crate::bench::format_duration;
+use crate::days::{Day, Parsed};
+use crate::memory::{self, Allocations};
+
+/// The outcome of running one part of a day, and how long that took.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub result: Result<Answer, ParseError>,
+    /// Time spent parsing the input, which both parts share.
+    pub parse_time: Duration,
+    /// `None` if the input failed to parse, so the part never ran.
+    pub solve_time: Option<Duration>,
+    /// What parsing allocated, if allocations were being counted, see [`crate::memory`].
+    pub parse_allocations: Option<Allocations>,
+    /// What solving allocated, likewise.
+    pub solve_allocations: Option<Allocations>,
+}
+impl Record {
+    pub fn status(&self) -> &'static str {
+        match &self.result {
+            Ok(answer) if answer.is_solved() => "solved",
+            Ok(_) => "unsolved",
+            Err(_) => "error",
+        }
+    }
+    
+    pub fn to_json(&self) -> Value {
+        let (answer, note, error) = match &self.result {
+            Ok(answer) => {
+                let value = match answer.value() {
+                    Answer::Integer(value) => json!(value),
+                    Answer::Text(text) => json!(text),
+                    _ => Value::Null,
+                };
+                
+                (value, answer.note().map(str::to_string), None)
+            },
+            Err(e) => (Value::Null, None, Some(e.to_string())),
+        };
+        
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "answer": answer,
+            "note": note,
+            "status": self.status(),
+            "error": error,
+            "parse_time_ns": self.parse_time.as_nanos() as u64,
+            "solve_time_ns": self.solve_time.map(|time| time.as_nanos() as u64),
+            "parse_allocations": self.parse_allocations.map(allocations_json),
+            "solve_allocations": self.solve_allocations.map(allocations_json),
+        })
+    }
+}
+fn allocations_json(allocations: Allocations) -> Value {
+    json!({
+        "count": allocations.count,
+        "bytes": allocations.bytes,
+        "peak_heap_bytes": allocations.peak_heap,
+    })
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day{:02} part{}: ", self.day, self.part)?;
+        
+        match &self.result {
+            Ok(answer) => match answer.note() {
+                Some(note) => write!(f, "{answer} ({note})"),
+                None => write!(f, "{answer}"),
+            },
+            Err(e) => write!(f, "error: {e}"),
+        }
+    }
+}
+
+/// Parses `input` and solves each of `parts`, returning the parsed input and a record for each part.
+pub fn solve<'a>(day: &Day, input: &'a str, parts: &[u8]) -> (Result<Box<dyn Parsed + 'a>, ParseError>, Vec<Record>) {
+    solve_with(day.number, || (day.parse)(input), parts)
+}
+
+/// Like [`solve`], but with the input parsed by `parse`, for a day run with its own options.
+pub fn solve_with<'a>(day: u8, parse: impl FnOnce() -> Result<Box<dyn Parsed + 'a>, ParseError>, parts: &[u8]) -> (Result<Box<dyn Parsed + 'a>, ParseError>, Vec<Record>) {
+    let start = Instant::now();
+    let (parsed, parse_allocations) = memory::measure(parse);
+    let parse_time = start.elapsed();
+    
+    let records = parts.iter()
+        .map(|&part| {
+            let (result, solve_time, solve_allocations) = match &parsed {
+                Ok(parsed) => {
+                    let start = Instant::now();
+                    let (result, allocations) = memory::measure(|| parsed.solve(part));
+                    
+                    (result, Some(start.elapsed()), allocations)
+                },
+                Err(e) => (Err(e.clone()), None, None),
+            };
+            
+            Record {
+                day,
+                part,
+                result,
+                parse_time,
+                solve_time,
+                parse_allocations,
+                solve_allocations,
+            }
+        })
+        .collect();
+    
+    (parsed, records)
+}
+
+/// One day's row of a [`summary`].
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub day: u8,
+    /// The day's records, or why it couldn't be run, like its input missing.
+    pub records: Result<Vec<Record>, String>,
+    /// How long the day took from reading its input to solving its last part.
+    pub time: Duration,
+}
+impl Row {
+    pub fn status(&self) -> &'static str {
+        match &self.records {
+            Err(_) => "skipped",
+            Ok(records) if records.iter().any(|record| record.result.is_err()) => "error",
+            Ok(records) if records.iter().all(|record| record.status() == "solved") => "solved",
+            Ok(_) => "unsolved",
+        }
+    }
+    
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status(), "skipped" | "error")
+    }
+    
+    /// What to show for `part`'s answer.
+    fn answer(&self, part: u8) -> String {
+        let record = match &self.records {
+            Ok(records) => records.iter().find(|record| record.part == part),
+            Err(_) => None,
+        };
+        
+        match record.map(|record| &record.result) {
+            Some(Ok(answer)) => answer.to_string(),
+            Some(Err(_)) => "error".to_string(),
+            None => "-".to_string(),
+        }
+    }
+}
+
+/// A table of each row's answers, status and time, in the order given, with why any were skipped
+/// or failed listed beneath it.
+pub fn summary(rows: &[Row]) -> String {
+    let mut cells = vec![["day", "part1", "part2", "status", "time"].map(String::from)];
+    for row in rows {
+        cells.push([
+            format!("day{:02}", row.day),
+            row.answer(1),
+            row.answer(2),
+            row.status().to_string(),
+            format_duration(row.time),
+        ]);
+    }
+    
+    let mut widths = [0; 5];
+    for line in &cells {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    
+    let mut output = String::new();
+    for line in &cells {
+        let [day, part1, part2, status, time] = line;
+        output += &format!("{day:<0$}  {part1:<1$}  {part2:<2$}  {status:<3$}  {time:>4$}\n", widths[0], widths[1], widths[2], widths[3], widths[4]);
+    }
+    for row in rows {
+        match &row.records {
+            Err(e) => output += &format!("day{:02}: {e}\n", row.day),
+            Ok(records) => for record in records.iter().filter(|record| record.result.is_err()) {
+                output += &format!("{record}\n");
+            },
+        }
+    }
+    
+    output
+}
\ No newline at end of file
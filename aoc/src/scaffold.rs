@@ -0,0 +1,164 @@
+//! `aoc new`, which starts a day's crate and registers it with the runner.
+
+use std::path::{Path, PathBuf};
+
+/// Every file in a new day's crate, relative to the crate, and what goes in it.
+pub fn files(number: u8) -> Vec<(String, String)> {
+    let krate = format!("day{number:02}");
+    let solution = format!("Day{number:02}");
+    
+    vec![
+        ("Cargo.toml".to_string(), format!(r#"[package]
+name = "{krate}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+common = {{ path = "../common" }}"#)),
+        ("src/main.rs".to_string(), format!(r#"fn main() {{
+    common::solution::run::<{krate}::{solution}>({krate}::SAMPLE, {krate}::INPUT_PATH);
+}}"#)),
+        ("src/lib.rs".to_string(), format!(r#"pub mod generator;
+
+use common::answer::Answer;
+use common::error::{{Line, ParseError}};
+use common::solution::Solution;
+
+const DAY: u8 = {number};
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+pub struct {solution};
+impl Solution for {solution} {{
+    type Input<'a> = Vec<&'a str>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {{
+        Ok(Line::all(DAY, input).map(|line| line.text).collect())
+    }}
+    
+    fn part1(_lines: &Self::Input<'_>) -> Result<Answer, ParseError> {{
+        Ok(Answer::Unsolved)
+    }}
+    
+    fn part2(_lines: &Self::Input<'_>) -> Result<Answer, ParseError> {{
+        Ok(Answer::Unsolved)
+    }}
+}}"#)),
+        ("src/generator.rs".to_string(), r#"use common::rng::Rng;
+
+/// `size` lines of one number each, until there's a real generator for this day's puzzle.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    
+    (0..size).map(|_| format!("{}\n", rng.below(1000))).collect()
+}"#.to_string()),
+        ("src/sample.txt".to_string(), String::new()),
+        ("src/answers.txt".to_string(), "# Recorded answers: `<sample|input> <part> <answer>`, or `pending` for a placeholder part.
+sample 1 pending
+sample 2 pending
+".to_string()),
+        (format!("tests/{krate}.rs"), format!(r#"use common::answer::Answer;
+use common::solution::Solution;
+use {krate}::{{{solution}, SAMPLE}};
+
+#[test]
+fn sample() {{
+    let input = {solution}::parse(SAMPLE).unwrap();
+    
+    // swap in the sample's answers from the puzzle text as each part is solved
+    assert_eq!({solution}::part1(&input).unwrap(), Answer::Unsolved);
+    assert_eq!({solution}::part2(&input).unwrap(), Answer::Unsolved);
+}}"#)),
+    ]
+}
+
+/// Adds `entry` among the lines `number_of` finds a day in, keeping them in order of that day.
+fn insert_entry(text: &str, entry: &str, number: u8, number_of: impl Fn(&str) -> Option<u8>) -> Result<String, String> {
+    let mut lines = text.split('\n').collect::<Vec<_>>();
+    
+    let entries = lines.iter()
+        .enumerate()
+        .filter_map(|(i, line)| number_of(line).map(|n| (i, n)))
+        .collect::<Vec<_>>();
+    let Some(&(last, _)) = entries.last() else {
+        return Err("there are no days listed to add to".to_string());
+    };
+    if entries.iter().any(|&(_, n)| n == number) {
+        return Err(format!("day {number} is already listed"));
+    }
+    
+    let at = entries.iter().find(|&&(_, n)| n > number).map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, entry);
+    
+    Ok(lines.join("\n"))
+}
+
+/// The day number in `prefix` followed by two digits, like `day07`.
+fn day_number(text: &str, prefix: &str) -> Option<u8> {
+    let digits = text.trim_start().strip_prefix(prefix)?.get(..2)?;
+    
+    digits.chars().all(|c| c.is_ascii_digit()).then(|| digits.parse().ok()).flatten()
+}
+
+/// One of the runner's files that lists every day.
+struct Registration {
+    file: &'static str,
+    /// The day a line is for, if it's one of the list's entries.
+    number_of: fn(&str) -> Option<u8>,
+    entry: String,
+}
+
+fn registrations(number: u8) -> [Registration; 3] {
+    [
+        Registration {
+            file: "aoc/Cargo.toml",
+            number_of: |line| day_number(line, "day").filter(|_| line.contains("path")),
+            entry: format!(r#"day{number:02} = {{ path = "../day{number:02}" }}"#),
+        },
+        Registration {
+            file: "aoc/src/days.rs",
+            number_of: |line| line.trim_start().strip_prefix("day!(")?.split_once(',')?.0.parse().ok(),
+            entry: format!("    day!({number}, day{number:02}::Day{number:02}),"),
+        },
+        Registration {
+            file: "aoc/tests/answers.rs",
+            number_of: |line| day_number(line, "day").filter(|_| line.trim_end().ends_with(',') && line.contains(':')),
+            entry: format!("    day{number:02}: {number},"),
+        },
+    ]
+}
+
+/// Creates day `number`'s crate in the workspace at `root` and registers it with the runner.
+///
+/// Nothing is written if the day already has a crate, or if any of the runner's files can't be
+/// updated. Returns every file created or changed.
+pub fn create(root: &Path, number: u8) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(format!("day{number:02}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    
+    // work out every change first, so a problem with one file doesn't leave the others half done
+    let mut updates = vec![];
+    for Registration { file, number_of, entry } in registrations(number) {
+        let path = root.join(file);
+        let text = std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let text = insert_entry(&text, &entry, number, number_of).map_err(|e| format!("{}: {e}", path.display()))?;
+        
+        updates.push((path, text));
+    }
+    for (file, contents) in files(number) {
+        updates.push((dir.join(file), contents));
+    }
+    
+    for (path, contents) in &updates {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("{}: {e}", parent.display()))?;
+        }
+        std::fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+    
+    Ok(updates.into_iter().map(|(path, _)| path).collect())
+}
\ No newline at end of file
@@ -0,0 +1,170 @@
+//! `aoc watch`, which re-runs a day whenever its source or input changes.
+
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use serde_json::Value;
+
+/// The crate at `dir`, then every crate it depends on by path, however indirectly, so a change to
+/// a shared crate like `grid` re-runs every day that uses it.
+///
+/// Only `path = "..."` is read out of each `Cargo.toml`, which is all the workspace's crates use to
+/// depend on each other.
+pub fn path_dependencies(dir: &Path) -> Vec<PathBuf> {
+    let mut crates = vec![dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf())];
+    let mut i = 0;
+    while i < crates.len() {
+        let manifest = std::fs::read_to_string(crates[i].join("Cargo.toml")).unwrap_or_default();
+        for line in manifest.lines() {
+            let Some((path, _)) = line.split_once("path = \"").and_then(|(_, rest)| rest.split_once('"')) else {
+                continue;
+            };
+            
+            let dependency = crates[i].join(path);
+            let dependency = dependency.canonicalize().unwrap_or(dependency);
+            if !crates.contains(&dependency) {
+                crates.push(dependency);
+            }
+        }
+        i += 1;
+    }
+    
+    crates
+}
+
+/// When each file under `paths` was last modified. Directories are walked, and missing paths are
+/// left out, so a file appearing or disappearing counts as a change too.
+pub fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut files = BTreeMap::new();
+    
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        
+        if metadata.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path, modified);
+        }
+    }
+    
+    files
+}
+
+/// Every file added, removed or modified between two [`snapshot`]s.
+pub fn changes(before: &BTreeMap<PathBuf, SystemTime>, after: &BTreeMap<PathBuf, SystemTime>) -> Vec<PathBuf> {
+    let mut changed = after.iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<_>>();
+    changed.extend(before.keys().filter(|path| !after.contains_key(*path)).cloned());
+    
+    changed
+}
+
+/// Each part's answer in the output of `aoc run --format json`, as it should be shown.
+pub fn outcomes(json: &str) -> Vec<(u8, String)> {
+    json.lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter_map(|record| {
+            let part = record["part"].as_u64()? as u8;
+            let shown = match (&record["answer"], &record["error"]) {
+                (_, Value::String(e)) => format!("error: {e}"),
+                (Value::String(text), _) => text.clone(),
+                (Value::Null, _) => record["status"].as_str()?.to_string(),
+                (answer, _) => answer.to_string(),
+            };
+            
+            Some((part, shown))
+        })
+        .collect()
+}
+
+/// The previous and new answers to each part side by side, marking those that changed.
+pub fn compare(previous: &[(u8, String)], new: &[(u8, String)]) -> String {
+    let find = |outcomes: &[(u8, String)], part| outcomes.iter()
+        .find(|(p, _)| *p == part)
+        .map_or("-".to_string(), |(_, shown)| shown.clone());
+    
+    let mut parts = previous.iter().chain(new).map(|(part, _)| *part).collect::<Vec<_>>();
+    parts.sort();
+    parts.dedup();
+    
+    let rows = parts.iter().map(|&part| (part, find(previous, part), find(new, part))).collect::<Vec<_>>();
+    let width = rows.iter().map(|(_, previous, _)| previous.chars().count()).max().unwrap_or(0).max("previous".len());
+    
+    let mut output = format!("{:<6} {:<width$}  new\n", "part", "previous");
+    for (part, previous, new) in rows {
+        let mark = if previous != new { "  (changed)" } else { "" };
+        output += &format!("part{part:<2} {previous:<width$}  {new}{mark}\n");
+    }
+    
+    output
+}
+
+/// Runs `aoc run <day> --format json` with `args`, rebuilding first if the source changed.
+///
+/// Returns the run's outcomes, or what cargo or the run printed to stderr if there were none.
+fn run(workspace: &Path, day: u8, args: &[String]) -> Result<Vec<(u8, String)>, String> {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    command.current_dir(workspace).args(["run", "--quiet", "--package", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.args(["--", "run", &day.to_string(), "--format", "json"]).args(args);
+    
+    let output = command.output().map_err(|e| format!("failed to start cargo: {e}"))?;
+    let outcomes = outcomes(&String::from_utf8_lossy(&output.stdout));
+    match outcomes.is_empty() {
+        true => Err(String::from_utf8_lossy(&output.stderr).trim_end().to_string()),
+        false => Ok(outcomes),
+    }
+}
+
+/// Runs day `day` with `args`, then again every time something under `paths` changes, checking for
+/// changes every `interval`. Only stops if interrupted.
+pub fn watch(workspace: &Path, day: u8, args: &[String], paths: &[PathBuf], interval: Duration) -> ! {
+    let clear = std::io::stdout().is_terminal();
+    
+    let mut files = snapshot(paths);
+    let mut previous = vec![];
+    let mut reason = "started watching".to_string();
+    loop {
+        let result = run(workspace, day, args);
+        
+        if clear {
+            print!("\x1b[2J\x1b[H");
+        }
+        println!("day{day:02}: {reason}");
+        match result {
+            Ok(outcomes) => {
+                print!("{}", compare(&previous, &outcomes));
+                previous = outcomes;
+            },
+            Err(e) => println!("{e}"),
+        }
+        println!("watching {} file(s) for changes...", files.len());
+        
+        let changed = loop {
+            std::thread::sleep(interval);
+            
+            let now = snapshot(paths);
+            let changed = changes(&files, &now);
+            files = now;
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        
+        let names = changed.iter()
+            .map(|path| path.strip_prefix(workspace).unwrap_or(path).display().to_string())
+            .collect::<Vec<_>>();
+        reason = format!("re-ran after {} changed", names.join(", "));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+use std::path::Path;
+use aoc::answers::{check, Input, Outcome};
+use aoc::days;
+
+/// Checks both parts of a day against its recorded answers, failing with a diff on a wrong answer.
+fn verify(number: u8, input: Input) {
+    let day = days::find(number).unwrap();
+    let text = match input {
+        Input::Sample => day.sample.to_string(),
+        Input::Real if !Path::new(day.input_path).exists() => {
+            eprintln!("day{number:02}: skipped, {} is missing", day.input_path);
+            return;
+        },
+        Input::Real => std::fs::read_to_string(day.input_path).unwrap(),
+    };
+    
+    let mut failures = vec![];
+    for part in 1..=2 {
+        let outcome = check(day, input, part, &text).unwrap();
+        let report = format!("day{number:02} {} part{part}: {outcome}", input.name());
+        eprintln!("{report}");
+        
+        if outcome.is_failure() {
+            failures.push(report);
+        }
+    }
+    
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+macro_rules! days {
+    ($($name:ident: $number:literal),* $(,)?) => {
+        mod sample {
+            use super::*;
+            
+            $(
+                #[test]
+                fn $name() {
+                    verify($number, Input::Sample);
+                }
+            )*
+        }
+        
+        mod input {
+            use super::*;
+            
+            $(
+                #[test]
+                fn $name() {
+                    verify($number, Input::Real);
+                }
+            )*
+        }
+    };
+}
+
+days! {
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day13: 13,
+}
+
+#[test]
+fn every_day_has_answers() {
+    for day in days::DAYS {
+        let answers = aoc::answers::Answers::parse(day.answers).unwrap_or_else(|e| panic!("day{:02}/{e}", day.number));
+        assert!(answers.get(Input::Sample, 1).is_some() || answers.get(Input::Sample, 2).is_some(), "day{:02} has no sample answers", day.number);
+    }
+}
+
+#[test]
+fn pending_is_not_a_pass() {
+    let day = days::find(7).unwrap();
+    let outcome = check(day, Input::Sample, 2, day.sample).unwrap();
+    
+    assert_eq!(outcome, Outcome::Pending);
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use aoc::days::{self, Day};
+use aoc::diff::{self, compare, minimize};
+use common::answer::Answer;
+use common::error::{Line, ParseError};
+use common::solution::{Solution, Variant};
+
+/// Sums a list of numbers, with a variant that forgets about every 7.
+struct Planted;
+impl Solution for Planted {
+    type Input<'a> = Vec<u64>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Line::all(0, input)
+            .flat_map(|line| line.text.split_whitespace().map(move |s| line.parse(s, "a number")))
+            .collect()
+    }
+    
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(input.iter().sum::<u64>().into())
+    }
+    
+    fn part2(_input: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(Answer::Unsolved)
+    }
+    
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant { name: "sevenless", part: 1, solve: |input| Ok(input.iter().filter(|&&n| n != 7).sum::<u64>().into()) }]
+    }
+}
+
+fn planted_generate(seed: u64, size: usize) -> String {
+    let mut rng = common::rng::Rng::new(seed);
+    
+    (0..size * 4)
+        .map(|_| (0..3).map(|_| rng.range(1..=9).to_string()).collect::<Vec<_>>().join(" ") + "\n")
+        .collect()
+}
+
+const PLANTED: Day = Day {
+    number: 0,
+    sample: "",
+    input_path: "",
+    answers: "",
+    parse: days::prepare::<Planted>,
+    generate: planted_generate,
+    variants: days::variant_names::<Planted>,
+};
+
+#[test]
+fn variants_agree_on_generated_inputs() {
+    for day in days::DAYS.iter().filter(|day| !(day.variants)().is_empty()) {
+        if let Some(counterexample) = diff::search(day, 10, 3) {
+            panic!("day{:02} {counterexample}", day.number);
+        }
+    }
+}
+
+#[test]
+fn planted_bug_is_found_and_minimized() {
+    let counterexample = diff::search(&PLANTED, 10, 3).expect("the planted bug wasn't found");
+    
+    assert_eq!(counterexample.input, "7\n");
+    assert_eq!(counterexample.disagreement.variant, "sevenless");
+    assert_eq!((counterexample.disagreement.expected.as_str(), counterexample.disagreement.actual.as_str()), ("7", "0"));
+}
+
+#[test]
+fn unparsable_inputs_never_disagree() {
+    assert_eq!(compare(&PLANTED, "1 2 x\n"), None);
+    assert_eq!(compare(&PLANTED, "1 2 3\n"), None);
+}
+
+#[test]
+fn minimize_removes_lines_then_words() {
+    assert_eq!(minimize("a b\nc x d\ne\n", |text| text.contains('x')), "x\n");
+    assert_eq!(minimize("x\n", |text| text.contains('x')), "x\n");
+}
\ No newline at end of file
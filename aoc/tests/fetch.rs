@@ -0,0 +1,67 @@
+mod mock;
+mod scratch;
+
+use aoc::config::{Config, DEFAULT_BASE_URL};
+use aoc::fetch::{fetch, Client};
+use mock::MockServer;
+use scratch::ScratchDir;
+
+#[test]
+fn downloads_with_the_session_cookie() {
+    let server = MockServer::start(|_| (200, "1 2 3\n".to_string()));
+    let dir = ScratchDir::new("fetch-download");
+    let path = dir.join("input.txt");
+    
+    let downloaded = fetch(&Client::new(&server.url, "abc123"), 3, &path).unwrap();
+    
+    assert!(downloaded);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2 3\n");
+    
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/3/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    assert!(requests[0].body.is_empty());
+}
+
+#[test]
+fn never_refetches_a_cached_input() {
+    let server = MockServer::start(|_| (200, "fresh\n".to_string()));
+    let dir = ScratchDir::new("fetch-cached");
+    let path = dir.join("input.txt");
+    std::fs::write(&path, "cached\n").unwrap();
+    
+    let downloaded = fetch(&Client::new(&server.url, "abc123"), 3, &path).unwrap();
+    
+    assert!(!downloaded);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "cached\n");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn leaves_nothing_behind_on_failure() {
+    let server = MockServer::start(|_| (404, "not yet".to_string()));
+    let dir = ScratchDir::new("fetch-missing");
+    let path = dir.join("input.txt");
+    
+    let error = fetch(&Client::new(&server.url, "abc123"), 25, &path).unwrap_err();
+    
+    assert!(error.contains("isn't available yet"), "{error}");
+    assert_eq!(dir.read_dir().unwrap().count(), 0);
+}
+
+#[test]
+fn environment_overrides_the_config_file() {
+    let config = Config::parse("# comment\nsession = from-file\n").unwrap();
+    assert_eq!(config.session.as_deref(), Some("from-file"));
+    assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    
+    let config = config.with_env(|name| match name {
+        "AOC_SESSION" => Some("from-env".to_string()),
+        "AOC_BASE_URL" => Some("http://127.0.0.1:1".to_string()),
+        _ => None,
+    });
+    assert_eq!(config.session.as_deref(), Some("from-env"));
+    assert_eq!(config.base_url, "http://127.0.0.1:1");
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use aoc::days;
+
+#[test]
+fn same_seed_same_input() {
+    for day in days::DAYS {
+        assert_eq!((day.generate)(7, 3), (day.generate)(7, 3), "day{:02}", day.number);
+        assert_ne!((day.generate)(7, 3), (day.generate)(8, 3), "day{:02}", day.number);
+    }
+}
+
+#[test]
+fn generated_inputs_solve() {
+    for day in days::DAYS {
+        for seed in 0..5 {
+            for size in 1..=3 {
+                let input = (day.generate)(seed, size);
+                for part in 1..=2 {
+                    if let Err(e) = day.solve(&input, part) {
+                        panic!("day{:02} part{part} failed on seed {seed}, size {size}: {e}\n{input}", day.number);
+                    }
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use common::answer::Answer;
+use common::error::ParseError;
+use aoc::days;
+
+fn solve(number: u8, input: &str, part: u8) -> Result<Answer, ParseError> {
+    days::find(number).unwrap().solve(input, part)
+}
+
+/// Checks that `input` is rejected at `line` and `column`, rather than panicking or looping.
+fn rejects(number: u8, input: &str, part: u8, (line, column): (usize, usize)) {
+    let e = solve(number, input, part).expect_err("malformed input was accepted");
+    assert_eq!((e.day, e.line, e.column), (number, line, column), "{e}");
+}
+
+#[test]
+fn day04_wins_past_the_last_card() {
+    assert_eq!(solve(4, "Card 1: 1 2 | 1 2", 2).unwrap(), Answer::Integer(1));
+}
+
+#[test]
+fn day05_almanacs() {
+    rejects(5, "seeds: 1 2\n\nseed-to-soil map:\n18446744073709551615 0 10", 1, (4, 24));
+    rejects(5, "seeds:\n\nseed-to-soil map:\n1 2 3", 1, (1, 7));
+    rejects(5, "seeds:\n\nseed-to-soil map:\n1 2 3", 2, (1, 7));
+    rejects(5, "seeds: 1 2 3\n\nseed-to-soil map:\n1 2 3", 2, (1, 13));
+    rejects(5, "seeds: 1 0\n\nseed-to-soil map:\n1 2 3", 2, (1, 10));
+    rejects(5, "seeds: 1 2 18446744073709551615 1\n\nseed-to-soil map:\n1 2 3", 2, (1, 33));
+    rejects(5, "seeds: 1 2\n3 4\n\nseed-to-soil map:\n1 2 3", 1, (2, 1));
+    rejects(5, "seeds: 1 2\n\nseed-soil map:\n1 2 3", 1, (3, 1));
+    rejects(5, "seeds: 1 2\n\nseed-to- map:\n1 2 3", 1, (3, 9));
+}
+
+#[test]
+fn day05_seeds_only_pair_up_in_part_two() {
+    let almanac = "seeds: 1 2 3\n\nseed-to-soil map:\n10 2 2";
+    assert_eq!(solve(5, almanac, 1).unwrap(), Answer::Integer(1));
+    assert_eq!(solve(5, &almanac.replace(" 1 2 3", " 3 0"), 1).unwrap(), Answer::Integer(0));
+}
+
+#[test]
+fn day08_missing_start() {
+    rejects(8, "LR\n\nBBB = (BBB, BBB)", 1, (3, 17));
+}
+
+#[test]
+fn day08_never_reaching_an_end() {
+    rejects(8, "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)", 1, (3, 1));
+    rejects(8, "L\n\n11A = (11B, 11B)\n11B = (11A, 11A)", 2, (3, 1));
+}
+
+#[test]
+fn day08_ghosts_that_never_line_up() {
+    // each ghost is on its end every other step, but never on the same step as the other
+    let network = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11Z, 11Z)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)";
+    let parsed = (days::find(8).unwrap().parse)(network).unwrap();
+    let (variant, result) = parsed.variants(2).remove(0);
+    
+    assert_eq!(variant, "lockstep");
+    let e = result.unwrap_err();
+    assert_eq!((e.line, e.column), (3, 1), "{e}");
+}
+
+#[test]
+fn day08_ghosts_that_dont_cycle_from_the_start() {
+    // `11A` first reaches an end after 2 steps but then every 3, so the least common multiple is wrong
+    let network = "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11C, 11C)\n11C = (11D, 11D)\n11D = (11Z, 11Z)\n22A = (22Z, 22Z)\n22Z = (22Z, 22Z)";
+    assert_eq!(solve(8, network, 2).unwrap(), Answer::Integer(2));
+    
+    let network = network.replace("22Z = (22Z, 22Z)", "22Z = (22B, 22B)\n22B = (22C, 22C)\n22C = (22D, 22D)\n22D = (22E, 22E)\n22E = (22Z, 22Z)");
+    assert_eq!(solve(8, &network, 2).unwrap(), Answer::Integer(11));
+}
+
+#[test]
+fn day09_overflowing_differences() {
+    rejects(9, "9223372036854775807 -1", 1, (1, 1));
+    rejects(9, "9223372036854775807 -1", 2, (1, 1));
+}
+
+#[test]
+fn day10_starts_and_dead_ends() {
+    rejects(10, "S.", 1, (1, 1));
+    rejects(10, "S-\n..", 1, (1, 1));
+    rejects(10, "..\n..", 1, (2, 3));
+    rejects(10, "S-7\n|.|\nL--", 1, (3, 3));
+    rejects(10, "S-7\n|.|\nL--", 2, (3, 3));
+}
+
+#[test]
+fn day13_several_lines_of_reflection() {
+    assert_eq!(solve(13, "###", 1).unwrap(), Answer::Integer(1));
+}
+
+#[test]
+fn day13_patterns_split_on_any_blank_line() {
+    assert_eq!(solve(13, "##\r\n..\r\n\r\n#.\r\n#.\r\n\r\n", 1).unwrap(), Answer::Integer(1 + 100));
+    assert_eq!(solve(13, "##\n..\n\n\n#.\n#.\n\n", 1).unwrap(), Answer::Integer(1 + 100));
+    rejects(13, "##\n..\n\n\n#.\n#x", 1, (6, 2));
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use std::hint::black_box;
+use aoc::days;
+use aoc::memory::{self, Counting};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn counts_what_was_allocated() {
+    assert!(memory::counting());
+    let (_, allocations) = memory::measure(|| {
+        let first = black_box(vec![0u8; 1000]);
+        drop(first);
+        let second = black_box(vec![0u8; 600]);
+        
+        (second, black_box(vec![0u8; 300]))
+    });
+    let allocations = allocations.unwrap();
+    
+    assert_eq!(allocations.count, 3);
+    assert_eq!(allocations.bytes, 1900);
+    assert_eq!(allocations.peak_heap, 1000, "the first vec was freed before the others were made");
+    
+    let (_, nothing) = memory::measure(|| black_box(1 + 1));
+    assert_eq!(nothing.unwrap().count, 0);
+}
+
+#[test]
+fn records_carry_allocations() {
+    let day = days::find(13).unwrap();
+    let (_, records) = aoc::report::solve(day, day.sample, &[1, 2]);
+    
+    for record in records {
+        assert!(record.parse_allocations.unwrap().count > 0);
+        assert!(record.solve_allocations.is_some());
+        assert!(record.to_json()["solve_allocations"]["bytes"].is_u64());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use std::time::Duration;
+use aoc::days;
+use aoc::pool;
+use aoc::report::{self, Row};
+
+#[test]
+fn results_keep_the_items_order() {
+    let items = (0..20u64).collect::<Vec<_>>();
+    
+    // the early items finish last, so finishing order is roughly the reverse of the items'
+    let results = pool::map(&items, 4, |&i| {
+        std::thread::sleep(Duration::from_millis(20 - i));
+        i * 2
+    });
+    
+    assert_eq!(results, items.iter().map(|i| i * 2).collect::<Vec<_>>());
+    assert!(pool::map(&[] as &[u64], 4, |&i| i).is_empty());
+}
+
+#[test]
+fn summary_is_in_day_order() {
+    let rows = pool::map(days::DAYS, 8, |day| Row {
+        day: day.number,
+        records: Ok(report::solve(day, day.sample, &[1, 2]).1),
+        time: Duration::ZERO,
+    });
+    let summary = report::summary(&rows);
+    let mut lines = summary.lines();
+    
+    assert!(lines.next().unwrap().starts_with("day    part1"));
+    for (day, line) in days::DAYS.iter().zip(lines) {
+        assert!(line.starts_with(&format!("day{:02}  ", day.number)), "{line}");
+    }
+    
+    let skipped = Row {
+        day: 6,
+        records: Err("input.txt is missing".to_string()),
+        time: Duration::ZERO,
+    };
+    assert!(skipped.is_failure());
+    assert!(report::summary(&[skipped]).ends_with("day06  -      -      skipped   0ns\nday06: input.txt is missing\n"));
+}
\ No newline at end of file
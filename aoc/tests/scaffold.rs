@@ -0,0 +1,58 @@
+mod scratch;
+
+use std::path::Path;
+use aoc::scaffold;
+use scratch::ScratchDir;
+
+/// A copy of the runner's registration files, in an otherwise empty workspace.
+fn scratch_workspace(name: &str) -> ScratchDir {
+    let root = ScratchDir::new(&format!("scaffold-{name}"));
+    
+    let aoc = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for file in ["Cargo.toml", "src/days.rs", "tests/answers.rs"] {
+        let path = root.join("aoc").join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::copy(aoc.join(file), path).unwrap();
+    }
+    
+    root
+}
+
+fn read(root: &Path, file: &str) -> String {
+    std::fs::read_to_string(root.join(file)).unwrap()
+}
+
+#[test]
+fn creates_and_registers_a_day() {
+    let root = scratch_workspace("create");
+    
+    let written = scaffold::create(&root, 12).unwrap();
+    assert_eq!(written.len(), 3 + scaffold::files(12).len());
+    assert!(read(&root, "day12/src/lib.rs").contains("pub struct Day12;"));
+    assert!(read(&root, "day12/src/main.rs").contains("day12::Day12"));
+    assert_eq!(read(&root, "day12/src/sample.txt"), "");
+    let tests = read(&root, "day12/tests/day12.rs");
+    assert!(tests.contains("use day12::{Day12, SAMPLE};"));
+    assert!(tests.contains("Day12::part1(&input)") && tests.contains("Day12::part2(&input)"));
+    
+    let days = read(&root, "aoc/src/days.rs");
+    assert!(days.contains("    day!(11, day11::Day11),\n    day!(12, day12::Day12),\n    day!(13, day13::Day13),"));
+    assert!(read(&root, "aoc/Cargo.toml").contains("day11 = { path = \"../day11\" }\nday12 = { path = \"../day12\" }\n"));
+    assert!(read(&root, "aoc/tests/answers.rs").contains("    day11: 11,\n    day12: 12,\n"));
+}
+
+#[test]
+fn refuses_to_overwrite_a_day() {
+    let root = scratch_workspace("overwrite");
+    scaffold::create(&root, 12).unwrap();
+    std::fs::write(root.join("day12/src/lib.rs"), "solved").unwrap();
+    let days = read(&root, "aoc/src/days.rs");
+    
+    assert!(scaffold::create(&root, 12).is_err());
+    assert_eq!(read(&root, "day12/src/lib.rs"), "solved");
+    assert_eq!(read(&root, "aoc/src/days.rs"), days);
+    
+    // day 5 has no crate in the scratch workspace, but the runner already lists it
+    assert!(scaffold::create(&root, 5).unwrap_err().contains("already listed"));
+    assert!(!root.join("day05").exists());
+}
\ No newline at end of file
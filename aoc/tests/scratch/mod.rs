@@ -0,0 +1,27 @@
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// An empty directory for a test to work in, removed again when it's dropped, even if the test fails.
+pub struct ScratchDir(PathBuf);
+impl ScratchDir {
+    /// `name` keeps the directories of tests running at the same time apart.
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        
+        Self(dir)
+    }
+}
+impl Deref for ScratchDir {
+    type Target = Path;
+    
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+mod mock;
+mod scratch;
+
+use aoc::fetch::Client;
+use aoc::ledger::{self, Ledger, Refusal, Verdict};
+use mock::MockServer;
+use scratch::ScratchDir;
+
+/// A stand-in for the site that treats 42 as the right answer.
+fn site() -> MockServer {
+    MockServer::start(|request| {
+        let answer = request.body.split('&')
+            .find_map(|pair| pair.strip_prefix("answer="))
+            .and_then(|answer| answer.parse::<i64>().ok())
+            .unwrap_or(0);
+        
+        let page = match answer {
+            42 => "<p>That's the right answer!</p>",
+            answer if answer > 42 => "<p>That's not the right answer; your answer is too high.</p>",
+            _ => "<p>That's not the right answer; your answer is too low.</p>",
+        };
+        
+        (200, page.to_string())
+    })
+}
+
+#[test]
+fn records_each_verdict() {
+    let server = site();
+    let client = Client::new(&server.url, "abc123");
+    let dir = ScratchDir::new("submit-records");
+    let path = dir.join("ledger.txt");
+    
+    assert_eq!(ledger::submit(&client, &path, 1, 1, "50"), Ok(Verdict::TooHigh));
+    assert_eq!(ledger::submit(&client, &path, 1, 1, "10"), Ok(Verdict::TooLow));
+    assert_eq!(ledger::submit(&client, &path, 1, 1, "42"), Ok(Verdict::Correct));
+    
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/1/answer");
+    assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    assert_eq!(requests[0].body, "level=1&answer=50");
+    
+    let ledger = Ledger::load(&path).unwrap();
+    assert_eq!(ledger.entries.len(), 3);
+    assert_eq!(ledger.bounds(1), (Some(10), Some(50)));
+}
+
+#[test]
+fn refuses_known_wrong_and_out_of_bounds_answers() {
+    let server = site();
+    let client = Client::new(&server.url, "abc123");
+    let dir = ScratchDir::new("submit-refuses");
+    let path = dir.join("ledger.txt");
+    
+    ledger::submit(&client, &path, 1, 2, "50").unwrap();
+    ledger::submit(&client, &path, 1, 2, "10").unwrap();
+    
+    for answer in ["50", "60", "10", "5"] {
+        assert!(ledger::submit(&client, &path, 1, 2, answer).is_err(), "{answer} was submitted");
+    }
+    assert_eq!(server.requests().len(), 2);
+    
+    // the other part has no bounds of its own
+    assert_eq!(ledger::submit(&client, &path, 1, 1, "60"), Ok(Verdict::TooHigh));
+}
+
+#[test]
+fn vets_against_the_ledger() {
+    let ledger = Ledger::parse("# part answer verdict\n1 100 too-high\n1 20 too-low\n1 abc wrong\n2 7 correct\n").unwrap();
+    
+    assert_eq!(ledger.vet(1, "50"), Ok(()));
+    assert_eq!(ledger.vet(1, "100"), Err(Refusal::KnownWrong(Verdict::TooHigh)));
+    assert_eq!(ledger.vet(1, "101"), Err(Refusal::TooHigh(100)));
+    assert_eq!(ledger.vet(1, "20"), Err(Refusal::KnownWrong(Verdict::TooLow)));
+    assert_eq!(ledger.vet(1, "abc"), Err(Refusal::KnownWrong(Verdict::Wrong)));
+    assert_eq!(ledger.vet(2, "7"), Err(Refusal::AlreadyCorrect));
+    assert_eq!(ledger.vet(2, "8"), Err(Refusal::KnownAnswer("7".to_string())));
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+mod scratch;
+
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use aoc::days;
+use aoc::watch::{changes, compare, outcomes, path_dependencies, snapshot};
+use scratch::ScratchDir;
+
+#[test]
+fn notices_added_modified_and_removed_files() {
+    let dir = ScratchDir::new("watch");
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("src/lib.rs"), "").unwrap();
+    std::fs::write(dir.join("input.txt"), "").unwrap();
+    
+    let paths = [dir.join("src"), dir.join("input.txt"), dir.join("missing.txt")];
+    let before = snapshot(&paths);
+    assert_eq!(before.len(), 2);
+    assert!(changes(&before, &snapshot(&paths)).is_empty());
+    
+    let mut after = before.clone();
+    *after.get_mut(&dir.join("src/lib.rs")).unwrap() = SystemTime::now() + Duration::from_secs(1);
+    after.remove(&dir.join("input.txt"));
+    after.insert(dir.join("src/new.rs"), SystemTime::now());
+    
+    let mut changed = changes(&before, &after);
+    changed.sort();
+    assert_eq!(changed, [dir.join("input.txt"), dir.join("src/lib.rs"), dir.join("src/new.rs")].map(PathBuf::from));
+}
+
+#[test]
+fn watches_the_crates_a_day_depends_on() {
+    let workspace = days::workspace_dir().canonicalize().unwrap();
+    let crates = |number| path_dependencies(&days::crate_dir(number)).into_iter().map(|dir| dir.strip_prefix(&workspace).unwrap().display().to_string()).collect::<Vec<_>>();
+    
+    assert_eq!(crates(6), ["day06", "common"]);
+    assert_eq!(crates(10), ["day10", "common", "grid", "visual"]);
+    assert_eq!(crates(3), ["day03", "common", "grid", "visual"]);
+}
+
+#[test]
+fn compares_answers_side_by_side() {
+    let json = r#"{"day":6,"part":1,"answer":288,"status":"solved","error":null}
+{"day":6,"part":2,"answer":null,"status":"error","error":"day06:1:1: expected `Time:`"}
+"#;
+    let new = outcomes(json);
+    assert_eq!(new, [(1, "288".to_string()), (2, "error: day06:1:1: expected `Time:`".to_string())]);
+    
+    let previous = [(1, "288".to_string()), (2, "unsolved".to_string())];
+    assert_eq!(compare(&previous, &new), "\
+part   previous  new
+part1  288       288
+part2  unsolved  error: day06:1:1: expected `Time:`  (changed)
+");
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+
+/// What a part came up with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// A placeholder for a part that hasn't been solved yet.
+    Unsolved,
+    /// Another answer, with a remark about it, like a known limitation of how it was found.
+    Noted(Box<Answer>, String),
+}
+impl Answer {
+    /// Attaches `note`, replacing any note already there.
+    pub fn with_note(self, note: impl Into<String>) -> Self {
+        match self {
+            Answer::Noted(answer, _) => Answer::Noted(answer, note.into()),
+            answer => Answer::Noted(Box::new(answer), note.into()),
+        }
+    }
+    
+    /// The answer itself, without any note.
+    pub fn value(&self) -> &Answer {
+        match self {
+            Answer::Noted(answer, _) => answer.value(),
+            answer => answer,
+        }
+    }
+    
+    pub fn note(&self) -> Option<&str> {
+        match self {
+            Answer::Noted(_, note) => Some(note),
+            _ => None,
+        }
+    }
+    
+    pub fn is_solved(&self) -> bool {
+        *self.value() != Answer::Unsolved
+    }
+}
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+            Answer::Noted(answer, _) => write!(f, "{answer}"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                /// Values too big for an `i64` are kept as text.
+                fn from(value: $ty) -> Self {
+                    i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Integer)
+                }
+            }
+        )*
+    };
+}
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// How many of a day's parts are solved.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Completion {
+    Complete,
+    /// Only one of the parts is solved.
+    Partial,
+    Unsolved,
+}
+impl Completion {
+    /// From whether each part is solved.
+    pub fn new(part1: bool, part2: bool) -> Self {
+        match (part1, part2) {
+            (true, true) => Completion::Complete,
+            (false, false) => Completion::Unsolved,
+            _ => Completion::Partial,
+        }
+    }
+}
+impl Display for Completion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Completion::Complete => f.pad("complete"),
+            Completion::Partial => f.pad("partial"),
+            Completion::Unsolved => f.pad("unsolved"),
+        }
+    }
+}
\ No newline at end of file
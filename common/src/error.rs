@@ -0,0 +1,131 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A token that didn't match what a parser expected, before its position in the input is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unexpected {
+    pub expected: String,
+    pub found: String,
+}
+impl Unexpected {
+    pub fn new(expected: impl Into<String>, found: impl Display) -> Self {
+        Self {
+            expected: expected.into(),
+            found: found.to_string(),
+        }
+    }
+}
+impl Display for Unexpected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.found.is_empty() {
+            write!(f, "expected {}, found nothing", self.expected)
+        } else {
+            write!(f, "expected {}, found `{}`", self.expected, self.found)
+        }
+    }
+}
+impl Error for Unexpected {}
+
+/// Malformed puzzle input, located by day, line and column.
+///
+/// Lines and columns count from 1. A line of 0 means the line isn't known yet, which is the case
+/// for errors coming out of a single line's [`FromStr`] impl, until [`ParseError::on_line`] is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+impl ParseError {
+    pub fn new(day: u8, line: usize, column: usize, unexpected: Unexpected) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            expected: unexpected.expected,
+            found: unexpected.found,
+        }
+    }
+    
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let unexpected = Unexpected::new(self.expected.as_str(), &self.found);
+        
+        write!(f, "day{:02}:{}:{}: {unexpected}", self.day, self.line, self.column)
+    }
+}
+impl Error for ParseError {}
+
+/// A line of a day's input, used to put a position on [`ParseError`]s.
+///
+/// Every `part` given to these methods must be a subslice of the line itself, so its column can be
+/// worked out from where it sits in memory.
+#[derive(Debug, Copy, Clone)]
+pub struct Line<'a> {
+    pub day: u8,
+    pub number: usize,
+    pub text: &'a str,
+}
+impl<'a> Line<'a> {
+    pub fn new(day: u8, number: usize, text: &'a str) -> Self {
+        Self {
+            day,
+            number,
+            text,
+        }
+    }
+    
+    /// Every line of `input`, numbered from 1.
+    pub fn all(day: u8, input: &'a str) -> impl Iterator<Item = Line<'a>> {
+        input.lines()
+            .enumerate()
+            .map(move |(i, text)| Line::new(day, i + 1, text))
+    }
+    
+    /// The column `part` starts at.
+    pub fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).saturating_sub(self.text.as_ptr() as usize).min(self.text.len());
+        
+        self.text[..offset].chars().count() + 1
+    }
+    
+    pub fn locate(&self, part: &str, unexpected: Unexpected) -> ParseError {
+        ParseError::new(self.day, self.number, self.column(part), unexpected)
+    }
+    
+    pub fn error(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        self.locate(part, Unexpected::new(expected, part))
+    }
+    
+    pub fn parse<T: FromStr>(&self, part: &'a str, expected: &str) -> Result<T, ParseError> {
+        part.parse().map_err(|_| self.error(part, expected))
+    }
+    
+    pub fn split_once(&self, part: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter).ok_or_else(|| self.error(part, format!("`{delimiter}`")))
+    }
+    
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        part.strip_prefix(prefix).ok_or_else(|| self.error(part, format!("`{prefix}`")))
+    }
+    
+    pub fn strip_suffix(&self, part: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        part.strip_suffix(suffix).ok_or_else(|| self.error(part, format!("`{suffix}`")))
+    }
+}
+
+/// Unwraps a day's answer, or reports the error and exits.
+pub fn or_exit<T>(result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    })
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The sample bundled into the day's binary.
+    Sample,
+    /// Standard input, selected with `-`.
+    Stdin,
+    File(PathBuf),
+}
+impl Source {
+    /// Parses `[PATH | -] [--sample]` from an argument list (without the program name).
+    ///
+    /// When neither a path nor `--sample` is given, `default_path` is used.
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I, default_path: &str) -> Result<Self, String> {
+        let mut source = None;
+        
+        for arg in args {
+            let next = match arg.as_str() {
+                "--sample" => Source::Sample,
+                "-" => Source::Stdin,
+                flag if flag.starts_with("--") => return Err(format!("unknown option: {flag}")),
+                path => Source::File(path.into()),
+            };
+            
+            if source.replace(next).is_some() {
+                return Err("expected at most one input (PATH, `-` or `--sample`)".to_string());
+            }
+        }
+        
+        Ok(source.unwrap_or_else(|| Source::File(default_path.into())))
+    }
+    
+    pub fn read(&self, sample: &str) -> Result<String, String> {
+        match self {
+            Source::Sample => Ok(sample.to_string()),
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input).map_err(|e| format!("failed to read stdin: {e}"))?;
+                
+                Ok(input)
+            },
+            Source::File(path) => std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display())),
+        }
+    }
+}
+
+/// Reads the input selected by the process arguments, exiting with a usage message on failure.
+pub fn load(sample: &str, default_path: &str) -> String {
+    load_from(std::env::args().skip(1).collect(), sample, default_path)
+}
+
+/// Like [`load`], but from `args` (without the program name), so a day can take its own options
+/// out of them first.
+pub fn load_from(args: Vec<String>, sample: &str, default_path: &str) -> String {
+    let result = crate::trace::init_from_args(args)
+        .and_then(|args| Source::from_args(args, default_path))
+        .and_then(|source| source.read(sample));
+    
+    result.unwrap_or_else(|e| exit_with_usage(&e))
+}
+
+/// Removes `name VALUE` from `args`, returning the value if it was there.
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|arg| arg == name) else {
+        return Ok(None);
+    };
+    if i + 1 == args.len() {
+        return Err(format!("{name} needs a value"));
+    }
+    
+    args.remove(i);
+    Ok(Some(args.remove(i)))
+}
+
+/// Removes every `name` from `args`, returning whether there were any.
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let before = args.len();
+    args.retain(|arg| arg != name);
+    
+    args.len() != before
+}
+
+/// Reports `error` along with how a day's binary is used, and exits.
+pub fn exit_with_usage(error: &str) -> ! {
+    eprintln!("error: {error}");
+    eprintln!("usage: {} [PATH | - | --sample] [--render] [-v...] [--trace TARGET]", std::env::args().next().unwrap_or_default());
+    std::process::exit(1);
+}
\ No newline at end of file
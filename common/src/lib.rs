@@ -0,0 +1,7 @@
+pub mod answer;
+pub mod error;
+pub mod input;
+pub mod parse;
+pub mod rng;
+pub mod solution;
+pub mod trace;
\ No newline at end of file
@@ -0,0 +1,190 @@
+//! Small combinators for the shapes puzzle inputs come in.
+//!
+//! Everything works on [`Part`]s, slices of a [`Line`] that remember where they came from, so each
+//! step can point an error at the exact column it gave up on, and each result can carry a [`Span`].
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use crate::error::{Line, ParseError, Unexpected};
+
+/// Where a piece of input sits, by line and the columns it covers. Both count from 1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+}
+impl Span {
+    /// The column just past the end.
+    pub fn end(&self) -> usize {
+        self.column + self.len
+    }
+}
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}-{}", self.line, self.column, self.end())
+    }
+}
+
+/// A parsed value, and where it was parsed from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+/// Just the values of a list of [`Spanned`]s.
+pub fn values<T>(spanned: Vec<Spanned<T>>) -> Vec<T> {
+    spanned.into_iter().map(|s| s.value).collect()
+}
+
+/// A slice of a line, which every combinator takes and returns.
+#[derive(Debug, Copy, Clone)]
+pub struct Part<'a> {
+    pub line: Line<'a>,
+    pub text: &'a str,
+}
+impl<'a> Part<'a> {
+    /// The whole of `line`.
+    pub fn of(line: Line<'a>) -> Self {
+        Self {
+            line,
+            text: line.text,
+        }
+    }
+    
+    fn sub(&self, text: &'a str) -> Self {
+        Self {
+            line: self.line,
+            text,
+        }
+    }
+    
+    pub fn span(&self) -> Span {
+        Span {
+            line: self.line.number,
+            column: self.line.column(self.text),
+            len: self.text.chars().count(),
+        }
+    }
+    
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.line.error(self.text, expected)
+    }
+    
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+    
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<Spanned<T>, ParseError> {
+        Ok(Spanned {
+            value: self.line.parse(self.text, expected)?,
+            span: self.span(),
+        })
+    }
+    
+    /// Parses with a [`FromStr`] impl that already says what it expected.
+    pub fn parse_as<T: FromStr<Err = Unexpected>>(&self) -> Result<Spanned<T>, ParseError> {
+        Ok(Spanned {
+            value: self.text.parse().map_err(|e| self.line.locate(self.text, e))?,
+            span: self.span(),
+        })
+    }
+    
+    pub fn prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        Ok(self.sub(self.line.strip_prefix(self.text, prefix)?))
+    }
+    
+    pub fn suffix(&self, suffix: &str) -> Result<Self, ParseError> {
+        Ok(self.sub(self.line.strip_suffix(self.text, suffix)?))
+    }
+    
+    pub fn split_once(&self, delimiter: &str) -> Result<(Self, Self), ParseError> {
+        let (left, right) = self.line.split_once(self.text, delimiter)?;
+        
+        Ok((self.sub(left), self.sub(right)))
+    }
+    
+    /// Each piece between `separator`s, trimmed.
+    pub fn separated(&self, separator: &'a str) -> impl Iterator<Item = Part<'a>> + 'a {
+        let part = *self;
+        
+        self.text.split(separator).map(move |s| part.sub(s.trim()))
+    }
+    
+    pub fn words(&self) -> impl Iterator<Item = Part<'a>> + 'a {
+        let part = *self;
+        
+        self.text.split_whitespace().map(move |s| part.sub(s))
+    }
+    
+    /// What's inside a group opened by `open` and closed by `close`, like `(BBB, CCC)`.
+    pub fn group(&self, open: &str, close: &str) -> Result<Self, ParseError> {
+        self.trim().prefix(open)?.suffix(close)
+    }
+    
+    /// A whitespace separated list of numbers.
+    pub fn integers<T: FromStr>(&self, expected: &str) -> Result<Vec<Spanned<T>>, ParseError> {
+        self.words().map(|word| word.parse(expected)).collect()
+    }
+    
+    /// A list of numbers after `label`, like `Time:  7  15  30`.
+    pub fn labelled<T: FromStr>(&self, label: &str) -> Result<Vec<Spanned<T>>, ParseError> {
+        self.trim().prefix(label)?.integers("a number")
+    }
+    
+    /// A key and value either side of `separator`, like `AAA = (BBB, CCC)`, both trimmed.
+    pub fn key_value(&self, separator: &str) -> Result<(Self, Self), ParseError> {
+        let (key, value) = self.split_once(separator)?;
+        let (key, value) = (key.trim(), value.trim());
+        if key.text.is_empty() {
+            return Err(key.error("a key"));
+        }
+        if value.text.is_empty() {
+            return Err(value.error("a value"));
+        }
+        
+        Ok((key, value))
+    }
+}
+
+/// A run of non-blank lines.
+#[derive(Debug, Clone)]
+pub struct Block<'a> {
+    pub lines: Vec<Line<'a>>,
+}
+impl<'a> Block<'a> {
+    /// The first line, without the `suffix` it has to end with, like `seed-to-soil map:`.
+    pub fn header(&self, suffix: &str) -> Result<Part<'a>, ParseError> {
+        Part::of(self.lines[0]).trim().suffix(suffix)
+    }
+    
+    /// Every line after the header.
+    pub fn body(&self) -> impl Iterator<Item = Part<'a>> + '_ {
+        self.lines[1..].iter().map(|&line| Part::of(line))
+    }
+    
+    /// The first and last line numbers.
+    pub fn lines_span(&self) -> (usize, usize) {
+        (self.lines[0].number, self.lines[self.lines.len() - 1].number)
+    }
+}
+
+/// Every block of lines in `input` separated by blank lines, keeping their original line numbers.
+pub fn blocks(day: u8, input: &str) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    
+    let mut lines = vec![];
+    for line in Line::all(day, input) {
+        if !line.text.trim().is_empty() {
+            lines.push(line);
+        } else if !lines.is_empty() {
+            blocks.push(Block { lines: std::mem::take(&mut lines) });
+        }
+    }
+    if !lines.is_empty() {
+        blocks.push(Block { lines });
+    }
+    
+    blocks
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use std::io::IsTerminal;
+use crate::answer::Answer;
+use crate::error::{or_exit, ParseError};
+
+/// A day's puzzle: how its input is parsed, and how each part is solved from that.
+pub trait Solution {
+    /// The parsed input, which may borrow from the text it was parsed from.
+    type Input<'a>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, ParseError>;
+    
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, ParseError>;
+    
+    /// Other ways of solving either part, which should always agree with `part1` and `part2`.
+    fn variants() -> Vec<Variant<Self>> {
+        vec![]
+    }
+    
+    /// Draws the input for a terminal, with what the parts found picked out in colour if `colour` is
+    /// set. `None` for days with nothing worth drawing.
+    fn render(_input: &Self::Input<'_>, _colour: bool) -> Option<String> {
+        None
+    }
+}
+
+/// Another implementation of a part, usually the naive one kept around to check a faster one against.
+pub struct Variant<S: Solution + ?Sized> {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: for<'a> fn(&S::Input<'a>) -> Result<Answer, ParseError>,
+}
+
+/// The whole of a day's binary: loads its input, then prints the answer to each part.
+///
+/// With `--render`, the input is drawn after the answers too.
+pub fn run<S: Solution>(sample: &str, default_path: &str) {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let render = crate::input::take_flag(&mut args, "--render");
+    let input = crate::input::load_from(args, sample, default_path);
+    let parsed = or_exit(S::parse(&input));
+    
+    print(&or_exit(S::part1(&parsed)));
+    print(&or_exit(S::part2(&parsed)));
+    
+    if render {
+        match S::render(&parsed, colour()) {
+            Some(drawing) => print!("{drawing}"),
+            None => eprintln!("warning: this day has nothing to render"),
+        }
+    }
+}
+
+/// Whether stdout should be coloured: only when it's a terminal, and `NO_COLOR` isn't set.
+pub fn colour() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// Prints `answer` on its own line, followed by its note if it has one.
+pub fn print(answer: &Answer) {
+    match answer.note() {
+        Some(note) => println!("{answer} ({note})"),
+        None => println!("{answer}"),
+    }
+}
\ No newline at end of file
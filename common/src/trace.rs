@@ -0,0 +1,115 @@
+use std::fmt::{Arguments, Display, Formatter};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+/// How detailed a message is, from the least to the most.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Warn = 1,
+    Info,
+    Debug,
+    Trace,
+}
+impl Level {
+    /// The most detailed level shown for a count of `-v` flags, if any.
+    pub fn from_verbosity(verbosity: u8) -> Option<Self> {
+        match verbosity {
+            0 => None,
+            1 => Some(Level::Info),
+            2 => Some(Level::Debug),
+            _ => Some(Level::Trace),
+        }
+    }
+}
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+/// The most detailed level shown for every target, with 0 meaning none.
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+/// Targets shown at every level, regardless of [`LEVEL`].
+static TARGETS: RwLock<Vec<String>> = RwLock::new(vec![]);
+
+/// Shows messages up to `level` from every target, and every message from `targets`.
+///
+/// Nothing is shown until this is called, so answers aren't mixed up with anything else.
+pub fn init(level: Option<Level>, targets: Vec<String>) {
+    LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+    *TARGETS.write().unwrap() = targets;
+}
+
+/// Whether messages at `level` from `target` are shown.
+///
+/// A target covers its own modules too, so `day10` also covers `day10::generator`.
+pub fn enabled(level: Level, target: &str) -> bool {
+    if level as u8 <= LEVEL.load(Ordering::Relaxed) {
+        return true;
+    }
+    
+    TARGETS.read().unwrap().iter().any(|traced| {
+        target.strip_prefix(traced.as_str()).is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+    })
+}
+
+/// Prints a message to stderr, if it's [`enabled`]. Use the macros instead, which fill in the target.
+pub fn log(level: Level, target: &str, args: Arguments) {
+    if enabled(level, target) {
+        eprintln!("{level:>5} {target}: {args}");
+    }
+}
+
+/// Takes `-v` (repeatable, or as `-vv`) and `--trace TARGET` out of `args`, and [`init`]s from them.
+///
+/// Returns the arguments that are left.
+pub fn init_from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Vec<String>, String> {
+    let mut verbosity = 0;
+    let mut targets = vec![];
+    let mut rest = vec![];
+    
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--trace" => targets.push(args.next().ok_or("--trace needs a target, like `day10`")?),
+            flag if flag.len() > 1 && flag.starts_with('-') && flag[1..].bytes().all(|b| b == b'v') => verbosity += flag.len() as u8 - 1,
+            _ => rest.push(arg),
+        }
+    }
+    
+    init(Level::from_verbosity(verbosity), targets);
+    Ok(rest)
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => {
+        $crate::trace::log($crate::trace::Level::Warn, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::trace::log($crate::trace::Level::Info, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::trace::log($crate::trace::Level::Debug, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::trace::log($crate::trace::Level::Trace, module_path!(), format_args!($($arg)+))
+    };
+}
\ No newline at end of file
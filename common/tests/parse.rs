@@ -0,0 +1,43 @@
+use common::error::Line;
+use common::parse::{self, Part, Span};
+
+#[test]
+fn results_carry_spans() {
+    let line = Line::new(6, 1, "Time:  7 15");
+    let times = Part::of(line).labelled::<usize>("Time:").unwrap();
+    assert_eq!(parse::values(times.clone()), [7, 15]);
+    assert_eq!(times[1].span, Span { line: 1, column: 10, len: 2 });
+    
+    let line = Line::new(8, 3, "AAA = (BBB, CCC)");
+    let (key, value) = Part::of(line).key_value("=").unwrap();
+    let (left, right) = value.group("(", ")").unwrap().split_once(",").unwrap();
+    assert_eq!(key.text, "AAA");
+    assert_eq!(left.span(), Span { line: 3, column: 8, len: 3 });
+    assert_eq!(right.trim().span(), Span { line: 3, column: 13, len: 3 });
+    
+    let line = Line::new(2, 1, "Game 1: 3 blue; 4 red, 1 green");
+    let sets = Part::of(line).key_value(":").unwrap().1.separated(";").collect::<Vec<_>>();
+    assert_eq!(sets[1].separated(",").map(|p| p.span().column).collect::<Vec<_>>(), [17, 24]);
+}
+
+#[test]
+fn errors_point_at_the_bad_part() {
+    let e = Part::of(Line::new(4, 2, "Card 1: 41 x8 | 83")).labelled::<usize>("Card 1:").unwrap_err();
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 12, "x8"));
+    
+    let e = Part::of(Line::new(8, 5, "AAA = BBB, CCC)")).key_value("=").unwrap().1.group("(", ")").unwrap_err();
+    assert_eq!((e.column, e.expected.as_str()), (7, "`(`"));
+    
+    let e = Part::of(Line::new(8, 1, " = (BBB, CCC)")).key_value("=").unwrap_err();
+    assert_eq!(e.expected, "a key");
+}
+
+#[test]
+fn blocks_keep_line_numbers() {
+    let blocks = parse::blocks(5, "seeds: 1 2\n\na map:\n1 2 3\n\n\nb map:\n4 5 6\n7 8 9");
+    assert_eq!(blocks.len(), 3);
+    assert_eq!(blocks[2].header(" map:").unwrap().text, "b");
+    assert_eq!(blocks[2].lines_span(), (7, 9));
+    assert_eq!(blocks[2].body().map(|p| p.line.number).collect::<Vec<_>>(), [8, 9]);
+    assert!(blocks[1].header(" maps:").is_err());
+}
\ No newline at end of file
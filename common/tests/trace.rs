@@ -0,0 +1,19 @@
+use common::trace::{self, enabled, Level};
+
+// the settings are global, so everything is checked in one test rather than racing between several
+#[test]
+fn levels_and_targets() {
+    trace::init(None, vec![]);
+    assert!(!enabled(Level::Warn, "day13"), "tracing should be silent by default");
+    
+    let rest = trace::init_from_args(["-vv", "-", "--trace", "day10"].map(String::from)).unwrap();
+    assert_eq!(rest, ["-"]);
+    assert!(enabled(Level::Debug, "day08"));
+    assert!(!enabled(Level::Trace, "day08"));
+    assert!(enabled(Level::Trace, "day10"));
+    assert!(enabled(Level::Trace, "day10::generator"));
+    assert!(!enabled(Level::Trace, "day100"));
+    
+    assert!(trace::init_from_args(["--trace".to_string()]).is_err());
+    trace::init(None, vec![]);
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+/// Which characters count as digits.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Digits {
+    /// Only `0` to `9`, as in the puzzle.
+    #[default]
+    Ascii,
+    /// The decimal digits of every script in [`ZEROS`] as well, like `٣` or `３`.
+    ///
+    /// Other characters that are numeric without being decimal digits, like `½` or `Ⅻ`, never count.
+    Unicode,
+}
+impl Digits {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ascii" => Some(Digits::Ascii),
+            "unicode" => Some(Digits::Unicode),
+            _ => None,
+        }
+    }
+    
+    /// The value of the digit that `text` starts with, and how many bytes it takes up.
+    pub fn digit_at(&self, text: &str) -> Option<(u32, usize)> {
+        match (text.as_bytes().first()?, self) {
+            (b @ b'0'..=b'9', _) => Some(((b - b'0') as u32, 1)),
+            (0x80.., Digits::Unicode) => {
+                let c = text.chars().next()?;
+                
+                unicode_digit(c).map(|value| (value, c.len_utf8()))
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The zero of each run of ten decimal digits outside ASCII, in the order of their code points.
+///
+/// These are the scripts whose digits are most likely to turn up, rather than every one in Unicode.
+pub const ZEROS: [char; 38] = [
+    '\u{0660}', // Arabic-Indic
+    '\u{06F0}', // Extended Arabic-Indic
+    '\u{07C0}', // NKo
+    '\u{0966}', // Devanagari
+    '\u{09E6}', // Bengali
+    '\u{0A66}', // Gurmukhi
+    '\u{0AE6}', // Gujarati
+    '\u{0B66}', // Oriya
+    '\u{0BE6}', // Tamil
+    '\u{0C66}', // Telugu
+    '\u{0CE6}', // Kannada
+    '\u{0D66}', // Malayalam
+    '\u{0DE6}', // Sinhala Lith
+    '\u{0E50}', // Thai
+    '\u{0ED0}', // Lao
+    '\u{0F20}', // Tibetan
+    '\u{1040}', // Myanmar
+    '\u{1090}', // Myanmar Shan
+    '\u{17E0}', // Khmer
+    '\u{1810}', // Mongolian
+    '\u{1946}', // Limbu
+    '\u{19D0}', // New Tai Lue
+    '\u{1A80}', // Tai Tham Hora
+    '\u{1A90}', // Tai Tham Tham
+    '\u{1B50}', // Balinese
+    '\u{1BB0}', // Sundanese
+    '\u{1C40}', // Lepcha
+    '\u{1C50}', // Ol Chiki
+    '\u{A620}', // Vai
+    '\u{A8D0}', // Saurashtra
+    '\u{A900}', // Kayah Li
+    '\u{A9D0}', // Javanese
+    '\u{A9F0}', // Myanmar Tai Laing
+    '\u{AA50}', // Cham
+    '\u{ABF0}', // Meetei Mayek
+    '\u{FF10}', // Fullwidth
+    '\u{1D7CE}', // Mathematical bold, then double-struck, sans-serif, sans-serif bold and monospace
+    '\u{1E950}', // Adlam
+];
+
+/// The value of `c` if it's one of the decimal digits in [`ZEROS`].
+pub fn unicode_digit(c: char) -> Option<u32> {
+    let c = c as u32;
+    let i = ZEROS.partition_point(|&zero| zero as u32 <= c).checked_sub(1)?;
+    let offset = c - ZEROS[i] as u32;
+    
+    match ZEROS[i] {
+        // the five styles of mathematical digits follow on from each other
+        '\u{1D7CE}' if offset < 50 => Some(offset % 10),
+        _ if offset < 10 => Some(offset),
+        _ => None,
+    }
+}
\ No newline at end of file
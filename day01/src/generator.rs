@@ -0,0 +1,30 @@
+use common::rng::Rng;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// `size` calibration lines of letters, digits and spelled out digits, each with at least one digit.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+    
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.range(1..=8) {
+            match rng.below(3) {
+                0 => line.push(char::from(b'1' + rng.below(9) as u8)),
+                1 => line += *rng.choose(&WORDS),
+                _ => line.extend((0..rng.range(1..=4)).map(|_| char::from(b'a' + rng.below(26) as u8))),
+            }
+        }
+        
+        if !line.bytes().any(|b| b.is_ascii_digit()) {
+            let at = rng.range(0..=line.len());
+            line.insert(at, char::from(b'1' + rng.below(9) as u8));
+        }
+        
+        output += &line;
+        output.push('\n');
+    }
+    
+    output
+}
\ No newline at end of file
@@ -0,0 +1,208 @@
+pub mod digits;
+pub mod generator;
+pub mod stream;
+pub mod vocabulary;
+
+use common::answer::Answer;
+use common::error::{Line, ParseError};
+use common::solution::{Solution, Variant};
+use digits::Digits;
+use vocabulary::Vocabulary;
+
+const DAY: u8 = 1;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+/// What counts as a number when reading calibration values.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    pub vocabulary: Vocabulary,
+    pub digits: Digits,
+}
+impl Config {
+    /// Part 2's rules, with the English words for digits.
+    pub fn english() -> Self {
+        Self {
+            vocabulary: Vocabulary::english(),
+            digits: Digits::Ascii,
+        }
+    }
+}
+
+/// Both parts' rules from the command line options, shared by the day's binary and the runner.
+///
+/// Part 1 only counts digits. Part 2 also counts the words in the vocabulary file at `path` and the
+/// `words` list, or English if there are neither. `digits` is `ascii` or `unicode`, for both.
+pub fn configs(path: Option<&str>, words: Option<&str>, digits: Option<&str>) -> Result<[Config; 2], String> {
+    let digits = match digits {
+        None => Digits::Ascii,
+        Some(name) => Digits::from_name(name).ok_or("--digits is either `ascii` or `unicode`")?,
+    };
+    let vocabulary = match (path, words) {
+        (None, None) => Vocabulary::english(),
+        (path, words) => Vocabulary::load(path, words)?,
+    };
+    
+    Ok([Config { digits, ..Config::default() }, Config { vocabulary, digits }])
+}
+
+/// A number found in a line, with the byte range it was found at.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub value: u64,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The number starting at byte `i` of `text`, either a digit or a word from `config`'s vocabulary.
+fn number_at(text: &str, i: usize, config: &Config) -> Option<Token> {
+    let (value, len) = match config.digits.digit_at(&text[i..]) {
+        Some((digit, len)) => (digit as u64, len),
+        None => config.vocabulary.match_at(text.as_bytes(), i)?,
+    };
+    
+    Some(Token {
+        value,
+        start: i,
+        end: i + len,
+    })
+}
+
+/// The first and last number in `text`, which are the same one if there's only one.
+///
+/// Every character is tried as the start of a number, in one pass and without copying the line, so
+/// words sharing letters like `twone` or `eightwo` count as both numbers whichever end they're at.
+pub fn scan(text: &str, config: &Config) -> Option<(Token, Token)> {
+    let mut numbers = None;
+    for i in (0..text.len()).filter(|&i| text.is_char_boundary(i)) {
+        if let Some(token) = number_at(text, i, config) {
+            let (first, _) = numbers.unwrap_or((token, token));
+            numbers = Some((first, token));
+        }
+    }
+    
+    numbers
+}
+
+/// Combines the first digit of `first` and last digit of `last` into a two digit value, see
+/// [`Vocabulary`] for how words worth more than one digit count.
+fn combine(first: Token, last: Token) -> usize {
+    (vocabulary::leading_digit(first.value) * 10 + last.value % 10) as usize
+}
+
+/// The error for a `line` without any numbers in it.
+fn no_numbers(line: &Line, config: &Config) -> ParseError {
+    match config.vocabulary.is_empty() {
+        true => line.error(line.text, "a line with digits"),
+        false => line.error(line.text, "a line with digits or number words"),
+    }
+}
+
+fn calibration_value(line: &Line, config: &Config) -> Result<usize, ParseError> {
+    match scan(line.text, config) {
+        Some((first, last)) => Ok(combine(first, last)),
+        None => Err(no_numbers(line, config)),
+    }
+}
+
+/// The sum of every line's calibration value.
+pub fn calibration_sum(lines: &[Line], config: &Config) -> Result<Answer, ParseError> {
+    lines.iter()
+        .map(|line| calibration_value(line, config))
+        .sum::<Result<usize, _>>()
+        .map(Answer::from)
+}
+
+/// A report of how each line's calibration value was found, listing every line that has none
+/// rather than stopping at the first, then the sum of the rest.
+///
+/// Returns the report and how many lines had no value.
+pub fn explain(lines: &[Line], config: &Config) -> (String, usize) {
+    let mut report = String::new();
+    let mut sum = 0;
+    let mut invalid = 0;
+    
+    for line in lines {
+        let Some((first, last)) = scan(line.text, config) else {
+            invalid += 1;
+            report += &format!("{}\n", no_numbers(line, config));
+            continue;
+        };
+        
+        let value = combine(first, last);
+        sum += value;
+        report += &format!(
+            "line {}: first `{}` at {}..{}, last `{}` at {}..{}, value {value}\n",
+            line.number,
+            &line.text[first.start..first.end], first.start, first.end,
+            &line.text[last.start..last.end], last.start, last.end,
+        );
+    }
+    report += &format!("sum {sum} over {} line(s), {invalid} without a value\n", lines.len() - invalid);
+    
+    (report, invalid)
+}
+
+/// Combines the first and last digit found in `text` (the contents of `line`) into a two digit value.
+fn replaced_calibration_value(line: &Line, text: &str) -> Result<usize, ParseError> {
+    let first = text.chars().find(|c| c.is_numeric()).and_then(|c| c.to_digit(10));
+    let last = text.chars().rfind(|c| c.is_numeric()).and_then(|c| c.to_digit(10));
+    
+    match (first, last) {
+        (Some(first), Some(last)) => Ok((first * 10 + last) as usize),
+        _ => Err(line.error(line.text, "a line with ASCII digits at both ends")),
+    }
+}
+
+const PAT_REPLACE: [(&str, &str); 10] = [
+    ("zero", "zero0zero"),
+    ("one", "one1one"),
+    ("two", "two2two"),
+    ("three", "three3three"),
+    ("four", "four4four"),
+    ("five", "five5five"),
+    ("six", "six6six"),
+    ("seven", "seven7seven"),
+    ("eight", "eight8eight"),
+    ("nine", "nine9nine"),
+];
+
+/// Part 2 by rewriting each word with its digit in the middle, so overlapping words keep their
+/// letters, then looking for plain digits.
+fn part2_replace(lines: &[Line]) -> Result<Answer, ParseError> {
+    lines.iter()
+        .map(|line| {
+            let mut text = line.text.to_string();
+            
+            for (pattern, replace) in PAT_REPLACE {
+                text = text.replace(pattern, replace);
+            }
+            
+            replaced_calibration_value(line, &text)
+        })
+        .sum::<Result<usize, _>>()
+        .map(Answer::from)
+}
+
+pub struct Day01;
+impl Solution for Day01 {
+    type Input<'a> = Vec<Line<'a>>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(Line::all(DAY, input).collect())
+    }
+    
+    fn part1(lines: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        calibration_sum(lines, &Config::default())
+    }
+    
+    fn part2(lines: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        calibration_sum(lines, &Config::english())
+    }
+    
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant { name: "replace", part: 2, solve: |lines| part2_replace(lines) }]
+    }
+}
\ No newline at end of file
@@ -1,49 +1,85 @@
-use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufRead, BufReader, IsTerminal};
+use common::answer::Answer;
+use common::error::or_exit;
+use common::input::{self, Source};
+use common::solution::{self, Solution};
+use common::trace;
+use day01::stream::{self, Progress};
+use day01::{Config, Day01};
 
-const INPUT: &'static str = include_str!("input.txt");
-
-fn part1() -> impl Display {
-    INPUT.lines()
-        .map(|line| {
-            let first = line.chars().find(|c| c.is_numeric()).unwrap();
-            let last = line.chars().rfind(|c| c.is_numeric()).unwrap();
-            
-            format!("{first}{last}").parse::<usize>().unwrap()
-        })
-        .sum::<usize>()
+fn main() {
+    // besides the usual options, these pick what counts as a number and how the input is read
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    if input::take_flag(&mut args, "--render") {
+        eprintln!("warning: this day has nothing to render");
+    }
+    let explain = input::take_flag(&mut args, "--explain");
+    let streaming = input::take_flag(&mut args, "--stream");
+    if explain && streaming {
+        input::exit_with_usage("--explain reads the whole input, so it can't be used with --stream");
+    }
+    let path = input::take_option(&mut args, "--vocabulary").unwrap_or_else(|e| input::exit_with_usage(&e));
+    let words = input::take_option(&mut args, "--words").unwrap_or_else(|e| input::exit_with_usage(&e));
+    let digits = input::take_option(&mut args, "--digits").unwrap_or_else(|e| input::exit_with_usage(&e));
+    let [part1, part2] = day01::configs(path.as_deref(), words.as_deref(), digits.as_deref()).unwrap_or_else(|e| input::exit_with_usage(&e));
+    
+    if streaming {
+        let result = trace::init_from_args(args)
+            .and_then(|args| Source::from_args(args, day01::INPUT_PATH))
+            .and_then(|source| stream(&source, [&part1, &part2]));
+        for sum in result.unwrap_or_else(|e| input::exit_with_usage(&e)) {
+            solution::print(&Answer::from(sum));
+        }
+        return;
+    }
+    
+    let input = input::load_from(args, day01::SAMPLE, day01::INPUT_PATH);
+    let lines = or_exit(Day01::parse(&input));
+    
+    if explain {
+        let (report, invalid) = day01::explain(&lines, &part2);
+        print!("{report}");
+        if invalid > 0 {
+            std::process::exit(1);
+        }
+        return;
+    }
+    
+    solution::print(&or_exit(day01::calibration_sum(&lines, &part1)));
+    solution::print(&or_exit(day01::calibration_sum(&lines, &part2)));
 }
 
-const PAT_REPLACE: [(&str, &str); 10] = [
-    ("zero", "zero0zero"),
-    ("one", "one1one"),
-    ("two", "two2two"),
-    ("three", "three3three"),
-    ("four", "four4four"),
-    ("five", "five5five"),
-    ("six", "six6six"),
-    ("seven", "seven7seven"),
-    ("eight", "eight8eight"),
-    ("nine", "nine9nine"),
-];
-
-fn part2() -> impl Display {
-    INPUT.lines()
-        .map(|line| {
-            let mut line = line.to_string();
-            
-            for (pattern, replace) in PAT_REPLACE {
-                line = line.replace(pattern, replace);
-            }
+/// The calibration sums of `source` under each of `configs`, read a line at a time.
+///
+/// Progress goes to stderr as it's made, when that's a terminal.
+fn stream<const N: usize>(source: &Source, configs: [&Config; N]) -> Result<[usize; N], String> {
+    let (reader, total): (Box<dyn BufRead>, _) = match source {
+        Source::Sample => (Box::new(day01::SAMPLE.as_bytes()), Some(day01::SAMPLE.len() as u64)),
+        Source::Stdin => (Box::new(std::io::stdin().lock()), None),
+        Source::File(path) => {
+            let file = File::open(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+            let total = file.metadata().ok().map(|metadata| metadata.len());
             
-            let first = line.chars().find(|c| c.is_numeric()).unwrap();
-            let last = line.chars().rfind(|c| c.is_numeric()).unwrap();
-            
-            format!("{first}{last}").parse::<usize>().unwrap()
-        })
-        .sum::<usize>()
-}
-
-fn main() {
-    println!("{}", part1());
-    println!("{}", part2());
+            (Box::new(BufReader::new(file)), total)
+        },
+    };
+    
+    let terminal = std::io::stderr().is_terminal();
+    let sums = stream::calibration_sums(reader, configs, |Progress { lines, bytes }| {
+        if !terminal {
+            return;
+        }
+        
+        let mib = bytes as f64 / (1 << 20) as f64;
+        match total {
+            Some(total) if total > 0 => eprint!("\r{mib:.1} MiB, {lines} lines ({:.0}%)", bytes as f64 / total as f64 * 100.0),
+            _ => eprint!("\r{mib:.1} MiB, {lines} lines"),
+        }
+    });
+    if terminal {
+        eprintln!();
+    }
+    
+    sums.map_err(|e| e.to_string())
 }
\ No newline at end of file
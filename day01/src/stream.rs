@@ -0,0 +1,72 @@
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use common::error::{Line, ParseError, Unexpected};
+use crate::{Config, DAY};
+
+/// How often a [`calibration_sums`] reports its progress, in bytes read.
+pub const PROGRESS_EVERY: u64 = 1 << 20;
+
+/// How far through its input a [`calibration_sums`] has got.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub lines: usize,
+    pub bytes: u64,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(std::io::Error),
+    Parse(ParseError),
+}
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "failed to read input: {e}"),
+            StreamError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+impl std::error::Error for StreamError {}
+
+/// The sum of every line's calibration value under each of `configs`, reading `reader` a line at a
+/// time, so the same as [`crate::calibration_sum`] on the whole input at once.
+///
+/// Every line is read into the same buffer, so memory use only grows with the longest line, not
+/// with the input. `progress` is called every [`PROGRESS_EVERY`] bytes, and once more at the end.
+pub fn calibration_sums<const N: usize>(mut reader: impl BufRead, configs: [&Config; N], mut progress: impl FnMut(Progress)) -> Result<[usize; N], StreamError> {
+    let mut sums = [0; N];
+    let mut done = Progress::default();
+    let mut reported = 0;
+    
+    let mut buffer = Vec::new();
+    loop {
+        buffer.clear();
+        let read = reader.read_until(b'\n', &mut buffer).map_err(StreamError::Io)?;
+        if read == 0 {
+            break;
+        }
+        done.lines += 1;
+        done.bytes += read as u64;
+        
+        // the same line endings as `str::lines`
+        let bytes = buffer.strip_suffix(b"\n").map(|bytes| bytes.strip_suffix(b"\r").unwrap_or(bytes)).unwrap_or(&buffer);
+        let text = std::str::from_utf8(bytes).map_err(|e| {
+            let column = String::from_utf8_lossy(&bytes[..e.valid_up_to()]).chars().count() + 1;
+            
+            StreamError::Parse(ParseError::new(DAY, done.lines, column, Unexpected::new("UTF-8 text", "invalid bytes")))
+        })?;
+        
+        let line = Line::new(DAY, done.lines, text);
+        for (sum, config) in sums.iter_mut().zip(configs) {
+            *sum += crate::calibration_value(&line, config).map_err(StreamError::Parse)?;
+        }
+        
+        if done.bytes - reported >= PROGRESS_EVERY {
+            reported = done.bytes;
+            progress(done);
+        }
+    }
+    progress(done);
+    
+    Ok(sums)
+}
\ No newline at end of file
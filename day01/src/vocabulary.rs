@@ -0,0 +1,128 @@
+/// The words that count as numbers when looking for a line's digits, and what each is worth.
+///
+/// A word can be worth more than one digit, like `twelve`. As the first number on a line it gives
+/// its leading digit, and as the last its final digit, so `twelve` alone is worth 12 and `twenty`
+/// alone is worth 20.
+///
+/// Where several words start at the same place, like `seven` and `seventeen`, the longest one is
+/// the one that counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    /// Longest first, so the first match at a position is the longest.
+    words: Vec<(String, u64)>,
+    /// Which bytes any word starts with, to skip most positions without comparing any words.
+    starts: [bool; 256],
+}
+impl Vocabulary {
+    pub fn new(words: impl IntoIterator<Item = (String, u64)>) -> Result<Self, String> {
+        let mut vocabulary = Self::default();
+        for (word, value) in words {
+            vocabulary.insert(word, value)?;
+        }
+        
+        Ok(vocabulary)
+    }
+    
+    /// The English words for zero to nine.
+    pub fn english() -> Self {
+        let words = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+        
+        Self::new(words.iter().zip(0..).map(|(word, value)| (word.to_string(), value))).unwrap()
+    }
+    
+    /// Reads `word=value` entries, separated by commas or new lines.
+    ///
+    /// Blank entries and anything after a `#` on a line are ignored, so this reads both a vocabulary
+    /// file and a list given on the command line, like `un=1,deux=2`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let multiline = text.lines().count() > 1;
+        
+        let mut vocabulary = Self::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            
+            for entry in line.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+                let error = |e: String| if multiline { format!("line {}: {e}", i + 1) } else { e };
+                
+                let (word, value) = entry.split_once('=').ok_or_else(|| error(format!("expected `word=value`, found `{entry}`")))?;
+                let value = value.trim().parse().map_err(|_| error(format!("expected a whole number, found `{}`", value.trim())))?;
+                vocabulary.insert(word.trim().to_string(), value).map_err(error)?;
+            }
+        }
+        
+        Ok(vocabulary)
+    }
+    
+    /// The words in the file at `path` and the `words` list together.
+    pub fn load(path: Option<&str>, words: Option<&str>) -> Result<Self, String> {
+        let mut vocabulary = match path {
+            Some(path) => {
+                let text = std::fs::read_to_string(path).map_err(|e| format!("failed to read {path}: {e}"))?;
+                Vocabulary::parse(&text).map_err(|e| format!("{path}: {e}"))?
+            },
+            None => Vocabulary::default(),
+        };
+        if let Some(words) = words {
+            vocabulary.extend(Vocabulary::parse(words).map_err(|e| format!("--words: {e}"))?)?;
+        }
+        
+        Ok(vocabulary)
+    }
+    
+    /// Adds `word`, worth `value`.
+    pub fn insert(&mut self, word: String, value: u64) -> Result<(), String> {
+        if word.is_empty() || word.bytes().any(|b| b.is_ascii_digit()) {
+            return Err(format!("`{word}` can't be a word, since it's empty or has digits in it"));
+        }
+        if self.words.iter().any(|(known, _)| *known == word) {
+            return Err(format!("`{word}` is in the vocabulary twice"));
+        }
+        
+        self.starts[word.as_bytes()[0] as usize] = true;
+        let at = self.words.partition_point(|(known, _)| known.len() >= word.len());
+        self.words.insert(at, (word, value));
+        
+        Ok(())
+    }
+    
+    /// Adds every word of `other`, which can't have any of the same words.
+    pub fn extend(&mut self, other: Vocabulary) -> Result<(), String> {
+        for (word, value) in other.words {
+            self.insert(word, value)?;
+        }
+        
+        Ok(())
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+    
+    /// The longest word starting at byte `i` of `text`, as its value and length in bytes.
+    pub fn match_at(&self, text: &[u8], i: usize) -> Option<(u64, usize)> {
+        if !self.starts[text[i] as usize] {
+            return None;
+        }
+        
+        self.words.iter()
+            .find(|(word, _)| text[i..].starts_with(word.as_bytes()))
+            .map(|(word, value)| (*value, word.len()))
+    }
+}
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self {
+            words: vec![],
+            starts: [false; 256],
+        }
+    }
+}
+
+/// The first digit of `value`.
+pub fn leading_digit(mut value: u64) -> u64 {
+    while value >= 10 {
+        value /= 10;
+    }
+    
+    value
+}
\ No newline at end of file
@@ -0,0 +1,146 @@
+use common::answer::Answer;
+use common::solution::Solution;
+use day01::digits::{self, Digits};
+use day01::stream::{self, StreamError};
+use day01::vocabulary::Vocabulary;
+use day01::{Config, Day01};
+
+fn part2(input: &str) -> Answer {
+    Day01::part2(&Day01::parse(input).unwrap()).unwrap()
+}
+
+#[test]
+fn overlapping_words_count_from_either_end() {
+    assert_eq!(part2("twone"), Answer::from(21usize));
+    assert_eq!(part2("eightwo"), Answer::from(82usize));
+    assert_eq!(part2("oneight"), Answer::from(18usize));
+    assert_eq!(part2("xtwonex"), Answer::from(21usize));
+    assert_eq!(part2("7eightwo"), Answer::from(72usize));
+    assert_eq!(part2("twonesevenineight"), Answer::from(28usize));
+    assert_eq!(part2("sevenine"), Answer::from(79usize));
+}
+
+#[test]
+fn words_only_count_in_part_two() {
+    let lines = Day01::parse("a1twob\nthree4").unwrap();
+    
+    assert_eq!(Day01::part1(&lines).unwrap(), Answer::from(11 + 44usize));
+    assert_eq!(Day01::part2(&lines).unwrap(), Answer::from(12 + 34usize));
+    
+    let e = Day01::part1(&Day01::parse("12\nnodigits").unwrap()).unwrap_err();
+    assert_eq!((e.line, e.column), (2, 1));
+}
+
+fn sum(input: &str, vocabulary: &Vocabulary) -> Answer {
+    let config = Config { vocabulary: vocabulary.clone(), digits: Digits::Ascii };
+    
+    day01::calibration_sum(&Day01::parse(input).unwrap(), &config).unwrap()
+}
+
+#[test]
+fn vocabularies_from_text() {
+    let french = Vocabulary::parse("# french\nun=1, deux=2\ntrois = 3\n").unwrap();
+    assert_eq!(sum("undeuxtrois\nxdeux", &french), Answer::from(13 + 22usize));
+    assert_eq!(sum("One2", &Vocabulary::parse("One=1").unwrap()), Answer::from(12usize));
+    assert_eq!(sum("one2", &Vocabulary::parse("One=1").unwrap()), Answer::from(22usize));
+    
+    assert_eq!(Vocabulary::parse("un=1\ndeux").unwrap_err(), "line 2: expected `word=value`, found `deux`");
+    assert!(Vocabulary::parse("un=1,un=2").unwrap_err().contains("twice"));
+    assert!(Vocabulary::parse("4ever=4").is_err());
+}
+
+#[test]
+fn configs_from_options() {
+    let lines = Day01::parse("un2\nthree").unwrap();
+    
+    let [part1, part2] = day01::configs(None, Some("un=1"), None).unwrap();
+    assert_eq!(day01::calibration_sum(&lines[..1], &part1).unwrap(), Answer::from(22usize));
+    assert_eq!(day01::calibration_sum(&lines[..1], &part2).unwrap(), Answer::from(12usize));
+    assert!(day01::calibration_sum(&lines, &part2).is_err(), "--words replaces English");
+    
+    let [_, english] = day01::configs(None, None, Some("unicode")).unwrap();
+    assert_eq!((english.digits, day01::calibration_sum(&lines[1..], &english).unwrap()), (Digits::Unicode, Answer::from(33usize)));
+    
+    assert!(day01::configs(None, None, Some("roman")).is_err());
+    assert!(day01::configs(Some("no/such/vocabulary"), None, None).unwrap_err().starts_with("failed to read"));
+}
+
+#[test]
+fn multi_digit_and_prefix_words() {
+    let vocabulary = Vocabulary::parse("seven=7,seventeen=17,twenty=20,ten=10").unwrap();
+    
+    // the longest word starting at a position counts, so `seventeen` isn't read as `seven`
+    assert_eq!(sum("seventeen", &vocabulary), Answer::from(17usize));
+    assert_eq!(sum("seventeenx3", &vocabulary), Answer::from(13usize));
+    assert_eq!(sum("5seventeen", &vocabulary), Answer::from(57usize));
+    assert_eq!(sum("seven", &vocabulary), Answer::from(77usize));
+    // first numbers give their leading digit, last ones their final digit
+    assert_eq!(sum("twenty", &vocabulary), Answer::from(20usize));
+    assert_eq!(sum("tenxtwenty", &vocabulary), Answer::from(10usize));
+    assert_eq!(sum("twentyten", &vocabulary), Answer::from(20usize));
+}
+
+#[test]
+fn unicode_digits_are_opt_in() {
+    let lines = Day01::parse("٣b٧\n３½5").unwrap();
+    let unicode = Config { digits: Digits::Unicode, ..Config::default() };
+    
+    assert_eq!(day01::calibration_sum(&lines, &unicode).unwrap(), Answer::from(37 + 35usize));
+    let e = day01::calibration_sum(&lines, &Config::default()).unwrap_err();
+    assert_eq!((e.line, e.found.as_str()), (1, "٣b٧"));
+    
+    for zero in digits::ZEROS {
+        for value in 0..10 {
+            let c = char::from_u32(zero as u32 + value).unwrap();
+            assert!(c.is_numeric(), "{c:?}");
+            assert_eq!(digits::unicode_digit(c), Some(value), "{c:?}");
+        }
+    }
+    assert_eq!(digits::unicode_digit('½'), None);
+    assert_eq!(digits::unicode_digit('\u{1D7FF}'), Some(9));
+    assert_eq!(digits::unicode_digit('\u{066A}'), None);
+}
+
+#[test]
+fn explains_every_line() {
+    let lines = Day01::parse("a1two\nnothing\n٣eightwo").unwrap();
+    let (report, invalid) = day01::explain(&lines, &Config::english());
+    
+    assert_eq!(invalid, 1);
+    assert_eq!(report, "\
+line 1: first `1` at 1..2, last `two` at 2..5, value 12
+day01:2:1: expected a line with digits or number words, found `nothing`
+line 3: first `eight` at 2..7, last `two` at 6..9, value 82
+sum 94 over 2 line(s), 1 without a value
+");
+}
+
+#[test]
+fn streaming_matches_the_whole_input() {
+    let input = day01::generator::generate(3, 3000) + "2twone\r\neightwo7";
+    let lines = Day01::parse(&input).unwrap();
+    let (part1, part2) = (Config::default(), Config::english());
+    
+    let mut reports = vec![];
+    let sums = stream::calibration_sums(input.as_bytes(), [&part1, &part2], |progress| reports.push(progress)).unwrap();
+    
+    assert_eq!(Answer::from(sums[0]), day01::calibration_sum(&lines, &part1).unwrap());
+    assert_eq!(Answer::from(sums[1]), day01::calibration_sum(&lines, &part2).unwrap());
+    let last = reports.last().unwrap();
+    assert_eq!((last.lines, last.bytes), (lines.len(), input.len() as u64));
+}
+
+#[test]
+fn streaming_reports_bad_lines() {
+    let english = Config::english();
+    
+    let Err(StreamError::Parse(e)) = stream::calibration_sums("1\nnothing\n".as_bytes(), [&english], |_| {}) else {
+        panic!("a line without numbers should fail");
+    };
+    assert_eq!((e.line, e.found.as_str()), (2, "nothing"));
+    
+    let Err(StreamError::Parse(e)) = stream::calibration_sums(&b"1\nab\xFF2\n"[..], [&english], |_| {}) else {
+        panic!("invalid UTF-8 should fail");
+    };
+    assert_eq!((e.line, e.column), (2, 3));
+}
\ No newline at end of file
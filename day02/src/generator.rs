@@ -0,0 +1,25 @@
+use common::rng::Rng;
+
+/// `size` games, each showing a handful of cubes at a time, a few times over.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+    
+    for id in 1..=size {
+        let sets = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                
+                colors[..rng.range(1..=3)].iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        
+        output += &format!("Game {id}: {}\n", sets.join("; "));
+    }
+    
+    output
+}
\ No newline at end of file
@@ -0,0 +1,100 @@
+pub mod generator;
+
+use std::cmp::max;
+use std::str::FromStr;
+use common::answer::Answer;
+use common::error::{Line, ParseError, Unexpected};
+use common::parse::Part;
+use common::solution::Solution;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+const DAY: u8 = 2;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+impl FromStr for Color {
+    type Err = Unexpected;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Self::Red),
+            "green" => Ok(Self::Green),
+            "blue" => Ok(Self::Blue),
+            _ => Err(Unexpected::new("`red`, `green` or `blue`", s)),
+        }
+    }
+}
+
+type Set = Vec<(usize, Color)>;
+type Game = Vec<Set>;
+
+const R: usize = Color::Red as usize;
+const G: usize = Color::Green as usize;
+const B: usize = Color::Blue as usize;
+
+pub struct Day02;
+impl Solution for Day02 {
+    /// Each game's ID, and the most cubes of each color shown at once.
+    type Input<'a> = Vec<(usize, [usize; 3])>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_maximums(input)
+    }
+    
+    fn part1(games: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(games.iter()
+            .filter(|(_, maximums)| !(maximums[R] > 12 || maximums[G] > 13 || maximums[B] > 14))
+            .map(|(id, _)| id)
+            .sum::<usize>().into())
+    }
+    
+    fn part2(games: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(games.iter()
+            .map(|(_, maximums)| maximums[R] * maximums[G] * maximums[B])
+            .sum::<usize>().into())
+    }
+}
+
+
+fn parse_game(line: Line) -> Result<(usize, Game), ParseError> {
+    let (gamenum, record) = Part::of(line).key_value(":")?;
+    let game_id = gamenum.prefix("Game ")?.parse("a game id")?.value;
+    
+    let mut game = Game::new();
+    for set in record.separated(";") {
+        let mut cubes = Set::new();
+        for numcolor in set.separated(",") {
+            let (num, color) = numcolor.split_once(" ")?;
+            
+            cubes.push((num.parse("a cube count")?.value, color.parse_as::<Color>()?.value));
+        }
+        game.push(cubes);
+    }
+    
+    Ok((game_id, game))
+}
+
+fn parse_maximums(input: &str) -> Result<Vec<(usize, [usize; 3])>, ParseError> {
+    Line::all(DAY, input)
+        .map(parse_game)
+        .map(|game| game.map(|(id, game)| {
+            let mut maximums = [0; 3];
+            
+            for set in game {
+                for (num, color) in set {
+                    let i = color as usize;
+                    maximums[i] = max(maximums[i], num);
+                }
+            }
+            
+            (id, maximums)
+        }))
+        .collect()
+}
\ No newline at end of file
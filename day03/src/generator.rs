@@ -0,0 +1,34 @@
+use common::rng::Rng;
+
+// gears are weighted up, or they would hardly ever sit between two numbers
+const SYMBOLS: &[u8] = b"***#+$/@=%&-";
+
+/// A `size` by `size` schematic of part numbers and symbols.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+    
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(size);
+        while row.len() < size {
+            if rng.chance(0.12) {
+                // numbers are kept apart, or they'd run together into one
+                let digits = rng.range(1..=3).min(size - row.len());
+                row.push(b'1' + rng.below(9) as u8);
+                row.extend((1..digits).map(|_| b'0' + rng.below(10) as u8));
+                if row.len() < size {
+                    row.push(b'.');
+                }
+            } else if rng.chance(0.06) {
+                row.push(*rng.choose(SYMBOLS));
+            } else {
+                row.push(b'.');
+            }
+        }
+        
+        output += &String::from_utf8(row).unwrap();
+        output.push('\n');
+    }
+    
+    output
+}
\ No newline at end of file
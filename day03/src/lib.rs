@@ -0,0 +1,152 @@
+pub mod generator;
+
+use std::collections::{HashMap, HashSet};
+use common::answer::Answer;
+use common::error::{ParseError, Unexpected};
+use common::solution::Solution;
+use grid::Grid;
+use visual::terminal::{Canvas, Cell, DIM, GREEN, RED, YELLOW};
+
+const DAY: u8 = 3;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+#[derive(Debug)]
+struct Span {
+    bits: Vec<char>,
+    value: usize,
+    pos: Vec<(usize, usize)>,
+    symbols: HashSet<(char, usize, usize)>,
+}
+impl PartialEq for Span {
+    fn eq(&self, other: &Self) -> bool {
+        self.pos.first().is_some_and(|lhs| other.pos.first().is_some_and(|rhs| lhs == rhs))
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<char>,
+    /// spans touching at least one symbol
+    spans: Vec<Span>,
+}
+impl Schematic {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut schematic = Schematic {
+            grid: Grid::try_parse(DAY, input, Ok)?,
+            spans: vec![],
+        };
+        
+        for y in 0..schematic.grid.height() {
+            let mut in_span = false;
+            for x in 0..schematic.grid.width() {
+                if schematic.grid[(x, y)].is_ascii_digit() {
+                    if !in_span {
+                        let span = schematic.detect_symbol(x, y)?;
+                        if !span.symbols.is_empty() {
+                            schematic.spans.push(span);
+                        }
+                        
+                        in_span = true;
+                    }
+                } else {
+                    in_span = false;
+                }
+            }
+        }
+        
+        Ok(schematic)
+    }
+    
+    /// Assumes (x, y) position is a number. So check for that before calling this function.
+    fn detect_symbol(&self, x: usize, y: usize) -> Result<Span, ParseError> {
+        let mut cx = x;
+        
+        let mut span = Span {
+            bits: Vec::with_capacity(4),
+            value: 0,
+            pos: Vec::with_capacity(4),
+            symbols: HashSet::with_capacity(4),
+        };
+        loop {
+            span.bits.push(self.grid[(cx, y)]);
+            span.pos.push((cx, y));
+            
+            for (x, y) in self.grid.neighbors8((cx, y)) {
+                let bit = self.grid[(x, y)];
+                if bit != '.' && !bit.is_ascii_digit() {
+                    span.symbols.insert((bit, x, y));
+                }
+            }
+            
+            if !self.grid.get(cx + 1, y).is_some_and(|next| next.is_ascii_digit()) {
+                break;
+            }
+            
+            cx += 1;
+        }
+        
+        let digits = String::from_iter(span.bits.iter());
+        span.value = digits.parse::<usize>()
+            .map_err(|_| ParseError::new(DAY, y + 1, x + 1, Unexpected::new("a part number", &digits)))?;
+        
+        Ok(span)
+    }
+}
+
+pub struct Day03;
+impl Solution for Day03 {
+    type Input<'a> = Schematic;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Schematic::new(input)
+    }
+    
+    fn part1(schematic: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(schematic.spans.iter()
+            .map(|span| span.value)
+            .sum::<usize>().into())
+    }
+    
+    fn part2(schematic: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut sum = 0;
+        for lhs_span in &schematic.spans {
+            for gear in lhs_span.symbols.iter().filter(|sym| sym.0 == '*') {
+                for rhs_span in &schematic.spans {
+                    if lhs_span != rhs_span && rhs_span.symbols.iter().find(|sym| gear == *sym).is_some() {
+                        sum += lhs_span.value * rhs_span.value;
+                    }
+                }
+            }
+        }
+        
+        // All gear ratios were counted twice, so we only need half of the result.
+        // 
+        // Duplicates could be checked pre-emptively (check previous commit), but doing so can only be
+        //   attempted within the deepest part of the algorithm. Thus for this circumstance, checking
+        //   overcomplicates the code for no meaningful benefit.
+        // 
+        // (Profiling would be needed to know which is ultimately faster)
+        Ok((sum / 2).into())
+    }
+    
+    fn render(schematic: &Self::Input<'_>, colour: bool) -> Option<String> {
+        let mut canvas = Canvas::from_grid(&schematic.grid, |_, &c| match c {
+            '.' => Cell::new(c).fg(DIM),
+            c if c.is_ascii_digit() => Cell::new(c).fg(DIM),
+            c => Cell::new(c).fg(RED),
+        });
+        canvas.recolour(schematic.spans.iter().flat_map(|span| span.pos.iter().copied()), GREEN);
+        
+        // gears are the `*`s touching exactly two part numbers
+        let mut touching = HashMap::new();
+        for &(symbol, x, y) in schematic.spans.iter().flat_map(|span| &span.symbols) {
+            *touching.entry((symbol, x, y)).or_insert(0) += 1;
+        }
+        canvas.highlight(touching.into_iter().filter(|&((symbol, _, _), parts)| symbol == '*' && parts == 2).map(|((_, x, y), _)| (x, y)), YELLOW);
+        
+        Some(canvas.render(colour))
+    }
+}
\ No newline at end of file
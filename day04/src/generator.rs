@@ -0,0 +1,27 @@
+use common::rng::Rng;
+
+/// `size` scratchcards with 10 winning numbers and 25 numbers each, out of 1 to 99.
+///
+/// No card wins copies of cards past the end of the table.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+    
+    for card in 1..=size {
+        let mut numbers = (1..=99).collect::<Vec<usize>>();
+        rng.shuffle(&mut numbers);
+        
+        let winning = &numbers[..10];
+        let mut have = numbers[10..35].to_vec();
+        let matches = rng.range(0..=10.min(size - card));
+        for (i, &number) in winning.iter().take(matches).enumerate() {
+            have[i] = number;
+        }
+        rng.shuffle(&mut have);
+        
+        let list = |numbers: &[usize]| numbers.iter().map(|n| format!("{n:>2}")).collect::<Vec<_>>().join(" ");
+        output += &format!("Card {card:>3}: {} | {}\n", list(winning), list(&have));
+    }
+    
+    output
+}
\ No newline at end of file
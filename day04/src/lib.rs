@@ -0,0 +1,62 @@
+pub mod generator;
+
+use common::answer::Answer;
+use common::error::{Line, ParseError};
+use common::parse::{self, Part};
+use common::solution::Solution;
+
+const DAY: u8 = 4;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+pub struct Day04;
+impl Solution for Day04 {
+    /// How many winning numbers each card has.
+    type Input<'a> = Vec<usize>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_cards(input).collect()
+    }
+    
+    fn part1(matches: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(matches.iter()
+            .map(|&matches| if matches > 0 {
+                2usize.pow(matches as u32 - 1)
+            } else {
+                0
+            })
+            .sum::<usize>().into())
+    }
+    
+    fn part2(matches: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut totals = vec![1; matches.len()];
+        
+        for i in 0..totals.len() {
+            // there are no cards past the end of the table to win copies of
+            let to_add = totals[i];
+            let end = (i + matches[i] + 1).min(totals.len());
+            for total in &mut totals[(i + 1)..end] {
+                *total += to_add;
+            }
+        }
+        
+        Ok(totals.into_iter().sum::<usize>().into())
+    }
+}
+
+
+fn parse_cards(input: &str) -> impl Iterator<Item = Result<usize, ParseError>> + '_ {
+    Line::all(DAY, input)
+        .map(|line| {
+            let (_, numbers) = Part::of(line).key_value(":")?;
+            let (winning, have) = numbers.split_once("|")?;
+            let winning = parse::values::<usize>(winning.integers("a number")?);
+            
+            Ok(have.integers::<usize>("a number")?
+                .iter()
+                .filter(|num| winning.contains(&num.value))
+                .count())
+        })
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use common::rng::Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// An almanac with `size` seed ranges, and the seven maps between them and their locations.
+///
+/// Ranges are kept short, so every seed can still be walked one at a time.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let space = 1000 * size.max(1);
+    
+    let seeds = (0..size)
+        .map(|_| format!("{} {}", rng.below(space), rng.range(1..=50)))
+        .collect::<Vec<_>>();
+    let mut output = format!("seeds: {}\n", seeds.join(" "));
+    
+    for name in MAPS {
+        output += &format!("\n{name} map:\n");
+        
+        // sources are cut from the space in order, so they never overlap
+        let mut start = 0;
+        let mut converts = vec![];
+        for _ in 0..rng.range(1..=5) {
+            start += rng.below(space / 5);
+            let range = rng.range(1..=space / 5);
+            converts.push((rng.below(space), start, range));
+            start += range;
+        }
+        rng.shuffle(&mut converts);
+        
+        for (dst, src, range) in converts {
+            output += &format!("{dst} {src} {range}\n");
+        }
+    }
+    
+    output
+}
\ No newline at end of file
@@ -0,0 +1,212 @@
+pub mod generator;
+
+use std::cmp::{max, min};
+use std::ops::Range;
+use common::answer::Answer;
+use common::error::{Line, ParseError, Unexpected};
+use common::parse::{self, Part, Span, Spanned};
+use common::solution::{Solution, Variant};
+
+const DAY: u8 = 5;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Map {
+    converts: Vec<Convertion>,
+}
+impl Map {
+    pub fn convert(&self, input: usize) -> usize {
+        self.converts.iter()
+            .find_map(|convert| convert.convert(input))
+            .unwrap_or(input)
+    }
+    
+    /// Converts whole ranges at once, splitting them wherever they cross the edge of a convertion.
+    pub fn convert_ranges(&self, mut unconverted: Vec<Range<usize>>) -> Vec<Range<usize>> {
+        let mut converted = Vec::with_capacity(unconverted.len());
+        for convert in &self.converts {
+            let src = convert.src..(convert.src + convert.range);
+            
+            let mut remaining = Vec::with_capacity(unconverted.len());
+            for range in unconverted {
+                let overlap = max(range.start, src.start)..min(range.end, src.end);
+                if overlap.is_empty() {
+                    remaining.push(range);
+                    continue;
+                }
+                
+                converted.push((convert.dst + (overlap.start - src.start))..(convert.dst + (overlap.end - src.start)));
+                remaining.extend([range.start..overlap.start, overlap.end..range.end].into_iter().filter(|range| !range.is_empty()));
+            }
+            unconverted = remaining;
+        }
+        
+        // anything no convertion covers maps to itself
+        converted.extend(unconverted);
+        converted
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Convertion {
+    dst: usize,
+    src: usize,
+    range: usize,
+}
+impl Convertion {
+    /// Where `input` ends up, if it's within the source range.
+    pub fn convert(&self, input: usize) -> Option<usize> {
+        let offset = input.checked_sub(self.src).filter(|&offset| offset < self.range)?;
+        
+        self.dst.checked_add(offset)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Almanac {
+    seeds: Vec<Spanned<usize>>,
+    /// The whole `seeds:` line, for errors about how many seeds it lists.
+    seeds_line: Span,
+    maps: Vec<Map>,
+}
+impl Almanac {
+    /// An error at the end of the `seeds:` line, about how many seeds it lists.
+    fn seed_count_error(&self, expected: &str) -> ParseError {
+        let found = match self.seeds.len() {
+            0 => "none".to_string(),
+            count => format!("{count} numbers"),
+        };
+        
+        ParseError::new(DAY, self.seeds_line.line, self.seeds_line.end(), Unexpected::new(expected, found))
+    }
+    
+    /// The seeds read as pairs of a start and a range length, the way part 2 reads them.
+    fn seed_ranges(&self) -> Result<Vec<Range<usize>>, ParseError> {
+        if self.seeds.is_empty() || !self.seeds.len().is_multiple_of(2) {
+            return Err(self.seed_count_error("pairs of a seed and a range length"));
+        }
+        
+        self.seeds.chunks_exact(2)
+            .map(|pair| {
+                let (start, len) = (pair[0], pair[1]);
+                if len.value == 0 {
+                    return Err(error_at(len, "a range length above 0"));
+                }
+                let end = start.value.checked_add(len.value).ok_or_else(|| error_at(len, "a range that fits below `usize::MAX`"))?;
+                
+                Ok(start.value..end)
+            })
+            .collect()
+    }
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    let blocks = parse::blocks(DAY, input);
+    let Some((first, rest)) = blocks.split_first() else {
+        return Err(Line::new(DAY, 1, "").error("", "`seeds:`"));
+    };
+    if let Some(&extra) = first.lines.get(1) {
+        return Err(Part::of(extra).error("a blank line after the seeds"));
+    }
+    
+    let line = Part::of(first.lines[0]);
+    let seeds = line.labelled::<usize>("seeds:")?;
+    
+    let mut maps = vec![];
+    for block in rest {
+        let (from, to) = block.header(" map:")?.split_once("-to-")?;
+        for category in [from, to] {
+            if category.text.is_empty() || !category.text.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(category.error("a category, like `seed`"));
+            }
+        }
+        
+        let mut map = Map::default();
+        for row in block.body() {
+            let nums = row.integers::<usize>("a number")?;
+            let [dst, src, range] = nums[..] else {
+                return Err(row.error("a destination, source and range length"));
+            };
+            if dst.value.checked_add(range.value).is_none() || src.value.checked_add(range.value).is_none() {
+                return Err(error_at(range, "a range that fits below `usize::MAX`"));
+            }
+            
+            map.converts.push(Convertion {
+                dst: dst.value,
+                src: src.value,
+                range: range.value,
+            })
+        }
+        maps.push(map);
+    }
+    
+    Ok(Almanac {
+        seeds,
+        seeds_line: line.span(),
+        maps,
+    })
+}
+
+/// An error at a number that parsed, but can't be used.
+fn error_at(number: Spanned<usize>, expected: &str) -> ParseError {
+    ParseError::new(DAY, number.span.line, number.span.column, Unexpected::new(expected, number.value))
+}
+
+pub struct Day05;
+impl Solution for Day05 {
+    type Input<'a> = Almanac;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_almanac(input)
+    }
+    
+    fn part1(almanac: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        if almanac.seeds.is_empty() {
+            return Err(almanac.seed_count_error("at least one seed"));
+        }
+        
+        let mut minimum = usize::MAX;
+        for seed in &almanac.seeds {
+            let mut output = seed.value;
+            for map in &almanac.maps {
+                output = map.convert(output);
+            }
+            minimum = min(output, minimum);
+        }
+        
+        Ok(minimum.into())
+    }
+    
+    fn part2(almanac: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut ranges = almanac.seed_ranges()?;
+        for map in &almanac.maps {
+            ranges = map.convert_ranges(ranges);
+        }
+        
+        Ok(ranges.iter().map(|range| range.start).min().expect("there's at least one pair of seeds").into())
+    }
+    
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant { name: "naive", part: 2, solve: part2_naive }]
+    }
+}
+
+/// Part 2 by converting every seed in every range, one at a time.
+fn part2_naive(almanac: &Almanac) -> Result<Answer, ParseError> {
+    let mut minimum = usize::MAX;
+    
+    almanac.seed_ranges()?
+        .into_iter()
+        .for_each(|range| range.for_each(|seed| {
+            let mut output = seed;
+            for map in &almanac.maps {
+                output = map.convert(output);
+            }
+            minimum = min(output, minimum);
+        }));
+    
+    Ok(minimum.into())
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+pub mod generator;
+
+use common::answer::Answer;
+use common::error::{Line, ParseError};
+use common::parse::{self, Part};
+use common::solution::{Solution, Variant};
+
+const DAY: u8 = 6;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+/// The `Time:` and `Distance:` lines, in that order.
+fn race_lines(input: &str) -> (Line<'_>, Line<'_>) {
+    let mut lines = Line::all(DAY, input);
+    
+    (lines.next().unwrap_or(Line::new(DAY, 1, "")), lines.next().unwrap_or(Line::new(DAY, 2, "")))
+}
+
+/// Parses the numbers after `label` as one number, ignoring the spaces between them.
+fn parse_kerned(line: Line, label: &str) -> Result<usize, ParseError> {
+    let numbers = Part::of(line).trim().prefix(label)?;
+    
+    numbers.text
+        .split_whitespace()
+        .fold(String::new(), |acc, val| acc + val)
+        .parse::<usize>()
+        .map_err(|_| numbers.error("a number"))
+}
+
+/// How many whole charge times beat `record` in a race lasting `time`.
+///
+/// The distance `charge * (time - charge)` is symmetric about `time / 2`, so this only needs the
+/// shortest winning charge, which is close to the lower root of the quadratic.
+fn ways(time: usize, record: usize) -> usize {
+    let beats = |charge: usize| charge * (time - charge) > record;
+    if !beats(time / 2) {
+        return 0;
+    }
+    
+    let discriminant = (time as f64).powi(2) - 4.0 * record as f64;
+    let mut shortest = ((time as f64 - discriminant.max(0.0).sqrt()) / 2.0).max(0.0) as usize;
+    shortest = shortest.min(time / 2);
+    // floating point can land either side of the root, so it's nudged onto the exact answer
+    while !beats(shortest) {
+        shortest += 1;
+    }
+    while shortest > 0 && beats(shortest - 1) {
+        shortest -= 1;
+    }
+    
+    time + 1 - 2 * shortest
+}
+
+/// The race times and record distances, as read by each part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Races {
+    times: Vec<usize>,
+    distances: Vec<usize>,
+    time: usize,
+    distance: usize,
+}
+
+pub struct Day06;
+impl Solution for Day06 {
+    type Input<'a> = Races;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (time_line, dist_line) = race_lines(input);
+        let times = Part::of(time_line).labelled("Time:")?;
+        let distances = Part::of(dist_line).labelled("Distance:")?;
+        if times.len() != distances.len() {
+            return Err(dist_line.error(dist_line.text, format!("{} distances", times.len())));
+        }
+        
+        Ok(Races {
+            times: parse::values(times),
+            distances: parse::values(distances),
+            time: parse_kerned(time_line, "Time:")?,
+            distance: parse_kerned(dist_line, "Distance:")?,
+        })
+    }
+    
+    fn part1(races: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let Races { times, distances, .. } = races;
+        
+        Ok(times.iter().zip(distances).map(|(&time, &record)| ways(time, record)).product::<usize>().into())
+    }
+    
+    fn part2(races: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(ways(races.time, races.distance).into())
+    }
+    
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant { name: "scan", part: 1, solve: part1_scan },
+            Variant { name: "scan", part: 2, solve: part2_scan },
+        ]
+    }
+}
+
+/// Part 1 by trying every charge time of every race.
+fn part1_scan(races: &Races) -> Result<Answer, ParseError> {
+    let Races { times, distances, .. } = races;
+    
+    let mut ways = vec![0usize; times.len()];
+    for race in 0..times.len() {
+        for charge_time in 1..times[race] {
+            let remaining = times[race] - charge_time;
+            let distance = remaining * charge_time;
+            
+            if distance > distances[race] {
+                ways[race] += 1;
+            }
+        }
+    }
+    
+    Ok(ways.into_iter().product::<usize>().into())
+}
+
+/// Part 2 by trying every charge time.
+fn part2_scan(races: &Races) -> Result<Answer, ParseError> {
+    let &Races { time, distance: dist, .. } = races;
+    
+    let mut ways = 0usize;
+    for charge_time in 0..time {
+        let remaining = time - charge_time;
+        let distance = remaining * charge_time;
+        
+        if distance > dist {
+            ways += 1;
+        }
+    }
+    
+    Ok(ways.into())
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+use common::rng::Rng;
+
+const CARDS: &[u8] = b"AKQJT98765432";
+
+/// `size` distinct hands, each with a bid of up to 1000.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.min(CARDS.len().pow(5));
+    let mut hands = HashSet::with_capacity(size);
+    let mut output = String::new();
+    
+    while hands.len() < size {
+        // a few repeated cards makes the stronger kinds turn up more often
+        let mut hand = (0..5).map(|_| *rng.choose(CARDS)).collect::<Vec<_>>();
+        for _ in 0..rng.below(3) {
+            hand[rng.below(5)] = hand[rng.below(5)];
+        }
+        let hand = String::from_utf8(hand).unwrap();
+        
+        if hands.insert(hand.clone()) {
+            output += &format!("{hand} {}\n", rng.range(1..=1000));
+        }
+    }
+    
+    output
+}
\ No newline at end of file
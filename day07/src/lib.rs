@@ -0,0 +1,159 @@
+pub mod generator;
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+use common::answer::Answer;
+use common::error::{Line, ParseError, Unexpected};
+use common::solution::Solution;
+
+const DAY: u8 = 7;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
+enum Kind {
+    FiveOf,
+    FourOf,
+    FullHouse,
+    ThreeOf,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+enum Card {
+    A,
+    K,
+    Q,
+    J,
+    T,
+    Nine,
+    Eight,
+    Seven,
+    Six,
+    Five,
+    Four,
+    Three,
+    Two,
+}
+impl TryFrom<char> for Card {
+    type Error = Unexpected;
+    
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'A' => Self::A,
+            'K' => Self::K,
+            'Q' => Self::Q,
+            'J' => Self::J,
+            'T' => Self::T,
+            '9' => Self::Nine,
+            '8' => Self::Eight,
+            '7' => Self::Seven,
+            '6' => Self::Six,
+            '5' => Self::Five,
+            '4' => Self::Four,
+            '3' => Self::Three,
+            '2' => Self::Two,
+            _ => return Err(Unexpected::new("a card (A, K, Q, J, T or 2-9)", value))
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Hand {
+    cards: [Card; 5],
+    bid: usize,
+}
+impl FromStr for Hand {
+    type Err = ParseError;
+    
+    /// The returned error's line is left as 0, since a hand doesn't know where it sits in the input.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(DAY, 0, s);
+        let (l, r) = line.split_once(s, " ")?;
+        
+        let cards = l.char_indices()
+            .map(|(i, c)| Card::try_from(c).map_err(|e| line.locate(&l[i..], e)))
+            .collect::<Result<Vec<Card>, _>>()?
+            .try_into()
+            .map_err(|_| line.error(l, "five cards"))?;
+        
+        Ok(Self {
+            cards,
+            bid: line.parse(r, "a bid")?,
+        })
+    }
+}
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Hand {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        match self.kind().cmp(&rhs.kind()) {
+            Ordering::Equal => {
+                for (l, r) in self.cards.iter().zip(rhs.cards.iter()) {
+                    let ord = l.cmp(r);
+                    if ord != Ordering::Equal {
+                        return ord;
+                    }
+                }
+                
+                Ordering::Equal
+            },
+            x => x
+        }
+    }
+}
+impl Hand {
+    fn kind(&self) -> Kind {
+        let mut counts = HashMap::with_capacity(5);
+        
+        for card in self.cards {
+            *counts.entry(card).or_insert(0usize) += 1;
+        }
+        let mut counts: Vec<usize> = counts.values().copied().collect();
+        counts.sort();
+        counts.reverse();
+        counts.resize(5, 0);
+        
+        match counts[..] {
+            [5, _, _, _, _] => Kind::FiveOf,
+            [4, _, _, _, _] => Kind::FourOf,
+            [3, 2, _, _, _] => Kind::FullHouse,
+            [3, 1, 1, _, _] => Kind::ThreeOf,
+            [2, 2, 1, _, _] => Kind::TwoPair,
+            [2, 1, 1, 1, _] => Kind::OnePair,
+            _ => Kind::HighCard,
+        }
+    }
+}
+
+pub struct Day07;
+impl Solution for Day07 {
+    type Input<'a> = Vec<Hand>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Line::all(DAY, input)
+            .map(|line| line.text.parse().map_err(|e: ParseError| e.on_line(line.number)))
+            .collect()
+    }
+    
+    fn part1(hands: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut hands = hands.clone();
+        hands.sort();
+        
+        Ok(hands.into_iter().rev().enumerate()
+            .map(|(i, hand)| (i + 1) * hand.bid)
+            .sum::<usize>().into())
+    }
+    
+    fn part2(_hands: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(Answer::Unsolved)
+    }
+}
\ No newline at end of file
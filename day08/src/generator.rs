@@ -0,0 +1,65 @@
+use common::rng::Rng;
+
+const MULTIPLIERS: [usize; 6] = [2, 3, 5, 7, 11, 13];
+
+/// A network walked by 2 to 4 ghosts, with `size` directions (up to 200) to follow.
+///
+/// Ghost 0 walks from `AAA` to `ZZZ`. Each ghost's loop is a different prime multiple of the
+/// directions long, and leads from its end straight back to where its start led, like the real
+/// puzzle's inputs.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let len = size.clamp(1, 200);
+    let dirs = (0..len).map(|_| rng.chance(0.5)).collect::<Vec<bool>>();
+    
+    let letters = |n: usize| (0..3).map(|i| char::from(b'A' + (n / 26usize.pow(i) % 26) as u8)).rev().collect::<String>();
+    let mut names = (0..26usize.pow(3)).map(letters).collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    let mut starts = names.iter().filter(|name| name.ends_with('A') && *name != "AAA").cloned();
+    let mut ends = names.iter().filter(|name| name.ends_with('Z') && *name != "ZZZ").cloned();
+    let mut others = names.iter().filter(|name| !name.ends_with('A') && !name.ends_with('Z')).cloned();
+    
+    let mut multipliers = MULTIPLIERS;
+    rng.shuffle(&mut multipliers);
+    
+    let mut nodes: Vec<(String, String, String)> = vec![];
+    let mut decoys = vec![];
+    for (ghost, &multiplier) in multipliers[..rng.range(2..=4)].iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (starts.next().unwrap(), ends.next().unwrap()),
+        };
+        
+        // the start, then the loop that's walked from it, ending with its end
+        let mut path = vec![start];
+        path.extend(others.by_ref().take(len * multiplier - 1));
+        path.push(end.clone());
+        path.push(path[1].clone());
+        
+        for (step, pair) in path.windows(2).enumerate() {
+            let decoy = others.next().unwrap();
+            let (left, right) = match dirs[step % len] {
+                true => (pair[1].clone(), decoy.clone()),
+                false => (decoy.clone(), pair[1].clone()),
+            };
+            
+            nodes.push((pair[0].clone(), left, right));
+            decoys.push(decoy);
+        }
+    }
+    
+    // decoys are never reached, so they can lead anywhere
+    for decoy in decoys {
+        let left = rng.choose(&nodes).0.clone();
+        let right = rng.choose(&nodes).0.clone();
+        nodes.push((decoy, left, right));
+    }
+    rng.shuffle(&mut nodes);
+    
+    let mut output = dirs.iter().map(|&dir| if dir { 'L' } else { 'R' }).collect::<String>() + "\n\n";
+    for (name, left, right) in nodes {
+        output += &format!("{name} = ({left}, {right})\n");
+    }
+    
+    output
+}
\ No newline at end of file
@@ -0,0 +1,253 @@
+pub mod generator;
+
+use std::collections::HashMap;
+use common::answer::Answer;
+use common::error::{Line, ParseError, Unexpected};
+use common::parse::{self, Part, Span};
+use common::solution::{Solution, Variant};
+use common::debug;
+
+const DAY: u8 = 8;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Dir {
+    L,
+    R,
+}
+impl TryFrom<char> for Dir {
+    type Error = Unexpected;
+    
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Self::L),
+            'R' => Ok(Self::R),
+            _ => Err(Unexpected::new("`L` or `R`", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Network<'a> {
+    dirs: Vec<Dir>,
+    nodes: HashMap<&'a str, (&'a str, &'a str)>,
+    /// Where each node is named, for pointing at a start that never reaches an end.
+    names: HashMap<&'a str, Span>,
+    /// Just past the end of the last line, where a node that's missing would have gone.
+    end: Span,
+}
+impl<'a> Network<'a> {
+    /// The node `dir` leads to from `name`.
+    fn next(&self, name: &str, dir: Dir) -> Result<&'a str, ParseError> {
+        let &(left, right) = self.nodes.get(name).ok_or_else(|| self.missing_node(name))?;
+        
+        Ok(match dir {
+            Dir::L => left,
+            Dir::R => right,
+        })
+    }
+    
+    /// How many steps it takes to walk from `start` to the first node `is_end` accepts, and which
+    /// node that is.
+    ///
+    /// There are only so many pairs of a node and a place in the directions, so a walk that's taken
+    /// more steps than that without reaching an end is going round a loop that never will.
+    fn steps(&self, start: &'a str, is_end: impl Fn(&str) -> bool, end: &str) -> Result<(usize, &'a str), ParseError> {
+        let limit = self.nodes.len() * self.dirs.len();
+        let mut sequence = self.dirs.iter().cycle();
+        
+        let mut steps = 0usize;
+        let mut current = start;
+        while !is_end(current) {
+            if steps > limit {
+                let span = self.names.get(start).copied().unwrap_or(self.end);
+                return Err(ParseError::new(DAY, span.line, span.column, Unexpected::new(format!("a path from `{start}` to {end}"), "a loop that never reaches one")));
+            }
+            
+            current = self.next(current, *sequence.next().unwrap())?;
+            steps += 1;
+        }
+        
+        Ok((steps, current))
+    }
+    
+    /// Whether a ghost that first reaches `end` after `first` steps is back on it, at the same place
+    /// in the directions, after `first` more, without passing another end on the way. If it is, it's
+    /// on an end at every multiple of `first` steps, and never in between.
+    fn cycles_from_start(&self, end: &str, first: usize) -> Result<bool, ParseError> {
+        if !first.is_multiple_of(self.dirs.len()) {
+            return Ok(false);
+        }
+        
+        let mut current = end;
+        for (i, dir) in self.dirs.iter().cycle().take(first).enumerate() {
+            if i > 0 && current.ends_with('Z') {
+                return Ok(false);
+            }
+            current = self.next(current, *dir)?;
+        }
+        
+        Ok(current == end)
+    }
+    
+    /// How many steps long the loop is that a walk from `start` ends up going round.
+    ///
+    /// By the time it's taken as many steps as there are pairs of a node and a place in the
+    /// directions, the walk has to be on its loop, so it's measured from there.
+    fn loop_len(&self, start: &str) -> Result<usize, ParseError> {
+        let limit = self.nodes.len() * self.dirs.len();
+        let mut current = start;
+        for dir in self.dirs.iter().cycle().take(limit) {
+            current = self.next(current, *dir)?;
+        }
+        
+        // `limit` is a whole number of times through the directions, so this is back at their start
+        let on_loop = current;
+        let mut len = 0usize;
+        for dir in self.dirs.iter().cycle() {
+            current = self.next(current, *dir)?;
+            len += 1;
+            if current == on_loop && len.is_multiple_of(self.dirs.len()) {
+                break;
+            }
+        }
+        
+        Ok(len)
+    }
+    
+    fn missing_node(&self, name: &str) -> ParseError {
+        ParseError::new(DAY, self.end.line, self.end.column, Unexpected::new(format!("a node named `{name}`"), "end of input"))
+    }
+}
+
+fn parse_network(input: &str) -> Result<Network<'_>, ParseError> {
+    let blocks = parse::blocks(DAY, input);
+    
+    let first = blocks.first().map_or(Line::new(DAY, 1, ""), |block| block.lines[0]);
+    let dirs = first.text.char_indices()
+        .map(|(i, c)| Dir::try_from(c).map_err(|e| first.locate(&first.text[i..], e)))
+        .collect::<Result<Vec<Dir>, _>>()?;
+    if dirs.is_empty() {
+        return Err(first.error(first.text, "a list of directions"));
+    }
+    
+    let mut nodes = HashMap::with_capacity(800);
+    let mut names = HashMap::with_capacity(800);
+    let mut references = Vec::with_capacity(1600);
+    for line in blocks.iter().flat_map(|block| &block.lines).skip(1) {
+        let (name, rhs) = Part::of(*line).key_value("=")?;
+        let (left, right) = rhs.group("(", ")")?.split_once(",")?;
+        let (left, right) = (left.trim(), right.trim());
+        
+        nodes.insert(name.text, (left.text, right.text));
+        names.insert(name.text, name.span());
+        references.push(left);
+        references.push(right);
+    }
+    
+    for name in references {
+        if !nodes.contains_key(name.text) {
+            return Err(name.error("the name of a node"));
+        }
+    }
+    
+    let last = Part::of(blocks.last().map_or(first, |block| block.lines[block.lines.len() - 1])).span();
+    
+    Ok(Network {
+        dirs,
+        nodes,
+        names,
+        end: Span { column: last.end(), len: 0, ..last },
+    })
+}
+
+pub struct Day08;
+impl Solution for Day08 {
+    type Input<'a> = Network<'a>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_network(input)
+    }
+    
+    fn part1(network: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(network.steps("AAA", |name| name == "ZZZ", "`ZZZ`")?.0.into())
+    }
+    
+    fn part2(network: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        // when every ghost is back on its end after as many steps again as it took the first time,
+        //   they all line up at the least common multiple of those. the puzzle's inputs are built
+        //   that way, and anything else falls back to stepping them all at once
+        let mut steps = 1usize;
+        for &start in network.nodes.keys().filter(|name| name.ends_with('A')) {
+            let (taken, end) = network.steps(start, |name| name.ends_with('Z'), "a node ending in `Z`")?;
+            if !network.cycles_from_start(end, taken)? {
+                debug!("`{start}` doesn't cycle from step 0, so stepping every ghost at once");
+                return part2_lockstep(network);
+            }
+            
+            steps = lcm(steps, taken);
+        }
+        
+        Ok(steps.into())
+    }
+    
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant { name: "lockstep", part: 2, solve: part2_lockstep }]
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+fn checked_lcm(a: usize, b: usize) -> Option<usize> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Part 2 by stepping every ghost at once until they're all on an end.
+///
+/// Within as many steps as there are pairs of a node and a place in the directions, every ghost is
+/// going round its loop. From then on they're all back where they were after the least common
+/// multiple of their loops' lengths, so if that many more steps go by without them all on an end at
+/// once, they never will be.
+fn part2_lockstep(network: &Network) -> Result<Answer, ParseError> {
+    let Network { dirs, nodes, names, .. } = network;
+    
+    let starts: Vec<_> = nodes.keys().copied().filter(|name| name.ends_with('A')).collect();
+    let mut period = 1usize;
+    for start in &starts {
+        period = checked_lcm(period, network.loop_len(start)?).unwrap_or(usize::MAX);
+    }
+    let limit = (nodes.len() * dirs.len()).saturating_add(period);
+    
+    let mut currents = starts.clone();
+    let mut sequence = dirs.iter().cycle();
+    let mut steps = 0usize;
+    while currents.iter().any(|cur| !cur.ends_with('Z')) {
+        if steps > limit {
+            let span = starts.iter().filter_map(|start| names.get(start)).min_by_key(|span| (span.line, span.column)).copied().unwrap_or(network.end);
+            return Err(ParseError::new(DAY, span.line, span.column, Unexpected::new("ghosts that all reach an end at once", "loops that never line up")));
+        }
+        
+        let next_dir = sequence.next().unwrap();
+        for current in &mut currents {
+            *current = network.next(current, *next_dir)?;
+        }
+        steps += 1;
+        if steps.is_multiple_of(10000000) {
+            debug!("currents: {currents:?} ({steps})");
+        }
+    }
+    
+    Ok(steps.into())
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use common::rng::Rng;
+
+/// `size` histories of 21 values, each from a polynomial of degree 1 to 6.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+    
+    for _ in 0..size {
+        // a sum of binomials n choose k has its k-th differences equal to that term's coefficient
+        let degree = rng.range(1..=6);
+        let coefficients = (0..=degree)
+            .map(|k| {
+                let bound = [30, 15, 8, 4, 3, 2, 1][k];
+                rng.range(0..=2 * bound) as isize - bound as isize
+            })
+            .collect::<Vec<_>>();
+        
+        let history = (0..21isize)
+            .map(|n| {
+                let mut binomial = 1;
+                let mut value = 0;
+                for (k, coefficient) in coefficients.iter().enumerate() {
+                    value += coefficient * binomial;
+                    binomial = binomial * (n - k as isize) / (k as isize + 1);
+                }
+                value.to_string()
+            })
+            .collect::<Vec<_>>();
+        
+        output += &history.join(" ");
+        output.push('\n');
+    }
+    
+    output
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+pub mod generator;
+
+use common::answer::Answer;
+use common::error::{Line, ParseError};
+use common::solution::Solution;
+
+const DAY: u8 = 9;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+/// Each history, with the line it came from so running out of room can be pointed at.
+fn parse_samples(input: &str) -> Result<Vec<(Line<'_>, Vec<isize>)>, ParseError> {
+    Line::all(DAY, input)
+        .map(|line| Ok((line, line.text.split(' ').map(|s| line.parse(s, "a number")).collect::<Result<_, _>>()?)))
+        .collect()
+}
+
+fn overflow(line: &Line) -> ParseError {
+    line.error(line.text, "values whose differences and predictions fit in an `isize`")
+}
+
+#[inline(always)]
+fn all_zeros(seq: &[isize]) -> bool {
+    seq.iter().all(|num| *num == 0)
+}
+
+pub struct Day09;
+impl Solution for Day09 {
+    type Input<'a> = Vec<(Line<'a>, Vec<isize>)>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_samples(input)
+    }
+    
+    fn part1(samples: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut total = 0isize;
+        for (line, init_seq) in samples {
+            let mut sequences = Vec::with_capacity(10);
+            sequences.push(init_seq.clone());
+            
+            while !all_zeros(sequences.last().unwrap()) {
+                let mut seq = Vec::with_capacity(sequences.last().unwrap().len() - 1);
+                
+                for window in sequences.last().unwrap().windows(2) {
+                    seq.push(window[1].checked_sub(window[0]).ok_or_else(|| overflow(line))?);
+                }
+                
+                sequences.push(seq);
+            }
+            
+            sequences.last_mut().unwrap().push(0);
+            
+            for i in (0..(sequences.len() - 1)).rev() {
+                let upper = *sequences[i].last().unwrap();
+                let lower = *sequences[i + 1].last().unwrap();
+                
+                sequences[i].push(upper.checked_add(lower).ok_or_else(|| overflow(line))?);
+            }
+            
+            total = total.checked_add(*sequences[0].last().unwrap()).ok_or_else(|| overflow(line))?;
+        }
+        
+        Ok(total.into())
+    }
+    
+    fn part2(samples: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut total = 0isize;
+        for (line, init_seq) in samples {
+            let mut sequences = Vec::with_capacity(10);
+            sequences.push(init_seq.clone());
+            
+            while !all_zeros(sequences.last().unwrap()) {
+                let mut seq = Vec::with_capacity(sequences.last().unwrap().len() - 1);
+                
+                for window in sequences.last().unwrap().windows(2) {
+                    seq.push(window[1].checked_sub(window[0]).ok_or_else(|| overflow(line))?);
+                }
+                
+                sequences.push(seq);
+            }
+            
+            sequences.last_mut().unwrap().insert(0, 0);
+            
+            for i in (0..(sequences.len() - 1)).rev() {
+                let upper = *sequences[i].first().unwrap();
+                let lower = *sequences[i + 1].first().unwrap();
+                
+                sequences[i].insert(0, upper.checked_sub(lower).ok_or_else(|| overflow(line))?);
+            }
+            
+            total = total.checked_add(*sequences[0].first().unwrap()).ok_or_else(|| overflow(line))?;
+        }
+        
+        Ok(total.into())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+use common::rng::Rng;
+
+const JUNK: &[u8] = b"|-LJF7...";
+
+/// A square field of pipes about `size` tiles wide, with one loop through the start tile among the junk.
+///
+/// The loop traces the outline of a blob of coarse cells, each spanning two tiles, so it never
+/// touches itself.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let coarse = ((size.max(3) - 1) / 2).max(1);
+    let side = 2 * coarse + 1;
+    
+    let blob = blob(&mut rng, coarse);
+    let inside = |x: isize, y: isize| x >= 0 && y >= 0 && blob.get(y as usize).and_then(|row| row.get(x as usize)).copied().unwrap_or(false);
+    
+    // a tile between two corners of the lattice is on the outline if exactly one of the cells it
+    //   borders is in the blob, and a corner is if its cells are split between in and out
+    let mut on_loop = vec![vec![false; side]; side];
+    for (y, row) in on_loop.iter_mut().enumerate() {
+        for (x, tile) in row.iter_mut().enumerate() {
+            let (cx, cy) = (x as isize / 2, y as isize / 2);
+            *tile = match (x % 2, y % 2) {
+                (1, 0) => inside(cx, cy - 1) != inside(cx, cy),
+                (0, 1) => inside(cx - 1, cy) != inside(cx, cy),
+                (0, 0) => {
+                    let cells = [inside(cx - 1, cy - 1), inside(cx, cy - 1), inside(cx - 1, cy), inside(cx, cy)];
+                    cells.contains(&true) && cells.contains(&false)
+                },
+                _ => false,
+            };
+        }
+    }
+    
+    let mut tiles = vec![vec![b'.'; side]; side];
+    let mut loop_tiles = vec![];
+    for y in 0..side {
+        for x in 0..side {
+            if !on_loop[y][x] {
+                continue;
+            }
+            let connected = |dx: isize, dy: isize| {
+                let (x, y) = (x as isize + dx, y as isize + dy);
+                x >= 0 && y >= 0 && on_loop.get(y as usize).and_then(|row| row.get(x as usize)).copied().unwrap_or(false)
+            };
+            
+            tiles[y][x] = match (connected(0, -1), connected(0, 1), connected(-1, 0), connected(1, 0)) {
+                (true, true, _, _) => b'|',
+                (_, _, true, true) => b'-',
+                (true, _, false, true) => b'L',
+                (true, _, true, false) => b'J',
+                (false, true, false, true) => b'F',
+                _ => b'7',
+            };
+            loop_tiles.push((x, y));
+        }
+    }
+    
+    let &(sx, sy) = rng.choose(&loop_tiles);
+    tiles[sy][sx] = b'S';
+    for y in 0..side {
+        for x in 0..side {
+            // junk next to the start is left as ground, so it can't look connected to it
+            let by_start = x.abs_diff(sx) + y.abs_diff(sy) == 1;
+            if !on_loop[y][x] && !by_start {
+                tiles[y][x] = *rng.choose(JUNK);
+            }
+        }
+    }
+    
+    tiles.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+/// Grows a random blob of cells in a `side` by `side` square, without holes or cells that only
+/// touch at a corner, so its outline is a single loop.
+fn blob(rng: &mut Rng, side: usize) -> Vec<Vec<bool>> {
+    let mut blob = vec![vec![false; side]; side];
+    blob[rng.below(side)][rng.below(side)] = true;
+    
+    let target = rng.range((side * side).div_ceil(4)..=(side * side).div_ceil(2));
+    let mut cells = 1;
+    let mut attempts = 0;
+    while cells < target && attempts < 20 * side * side {
+        attempts += 1;
+        let (x, y) = (rng.below(side), rng.below(side));
+        let touching = [(0, -1), (0, 1), (-1, 0), (1, 0)].iter().any(|&(dx, dy): &(isize, isize)| {
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            x >= 0 && y >= 0 && blob.get(y as usize).and_then(|row| row.get(x as usize)).copied().unwrap_or(false)
+        });
+        if blob[y][x] || !touching {
+            continue;
+        }
+        
+        blob[y][x] = true;
+        if pinched(&blob) || holed(&blob) {
+            blob[y][x] = false;
+        } else {
+            cells += 1;
+        }
+    }
+    
+    blob
+}
+
+/// Whether any two cells only touch diagonally, in or out of the blob.
+fn pinched(blob: &[Vec<bool>]) -> bool {
+    let side = blob.len();
+    let at = |x: usize, y: usize| x >= 1 && y >= 1 && x <= side && y <= side && blob[y - 1][x - 1];
+    
+    // checked over a border of empty cells, since the outside counts too
+    (0..=side).any(|y| (0..=side).any(|x| {
+        let (a, b, c, d) = (at(x, y), at(x + 1, y), at(x, y + 1), at(x + 1, y + 1));
+        a == d && b == c && a != b
+    }))
+}
+
+/// Whether any empty cells are cut off from the outside.
+fn holed(blob: &[Vec<bool>]) -> bool {
+    let side = blob.len() + 2;
+    let empty = |x: usize, y: usize| x == 0 || y == 0 || x == side - 1 || y == side - 1 || !blob[y - 1][x - 1];
+    
+    let mut seen = vec![vec![false; side]; side];
+    let mut stack = vec![(0usize, 0usize)];
+    seen[0][0] = true;
+    while let Some((x, y)) = stack.pop() {
+        for (nx, ny) in [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)] {
+            if nx < side && ny < side && !seen[ny][nx] && empty(nx, ny) {
+                seen[ny][nx] = true;
+                stack.push((nx, ny));
+            }
+        }
+    }
+    
+    (0..side).any(|y| (0..side).any(|x| empty(x, y) && !seen[y][x]))
+}
\ No newline at end of file
@@ -0,0 +1,298 @@
+pub mod generator;
+
+use std::fmt::Display;
+use common::answer::Answer;
+use common::error::{ParseError, Unexpected};
+use common::solution::Solution;
+use common::{debug, trace};
+use grid::Grid;
+use visual::terminal::{Canvas, Cell, GREEN, RED, YELLOW};
+use visual::Frame;
+
+const DAY: u8 = 10;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample1.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Vert,
+    Hori,
+    NE,
+    NW,
+    SE,
+    SW,
+    Ground,
+    Start,
+}
+impl TryFrom<char> for Tile {
+    type Error = Unexpected;
+    
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        use Tile::*;
+        Ok(match value {
+            '|' => Vert,
+            '-' => Hori,
+            'L' => NE,
+            'J' => NW,
+            'F' => SE,
+            '7' => SW,
+            '.' => Ground,
+            'S' => Start,
+            
+            _ => return Err(Unexpected::new("a pipe, `.` or `S`", value))
+        })
+    }
+}
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        use Tile::*;
+        match value {
+            Vert => '║',
+            Hori => '═',
+            NE => '╚',
+            NW => '╝',
+            SE => '╔',
+            SW => '╗',
+            Ground => '░',
+            Start => 'S',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    pub data: Grid<(Tile, Option<usize>)>,
+    start: (usize, usize),
+}
+impl Maze {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let data = Grid::try_parse(DAY, input, |c| Ok((c.try_into()?, None)))?;
+        let Some(start) = data.position(|cell| cell.0 == Tile::Start) else {
+            let end = (input.lines().last().map_or(0, |line| line.chars().count()), input.lines().count().saturating_sub(1));
+            return Err(error_at(end, "a start tile `S`", "end of input"));
+        };
+        
+        let maze = Self {
+            data,
+            start,
+        };
+        let joined = maze.neighbors(start.0, start.1, None).len();
+        if joined != 2 {
+            return Err(error_at(start, "`S` joined to exactly two pipes", format!("`S` joined to {joined}")));
+        }
+        
+        Ok(maze)
+    }
+    
+    pub fn get(&self, x: usize, y: usize) -> Option<(Tile, Option<usize>)> {
+        self.data.get(x, y).copied()
+    }
+    
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut (Tile, Option<usize>)> {
+        self.data.get_mut(x, y)
+    }
+    
+    pub fn start(&self) -> (usize, usize) {
+        self.start
+    }
+    
+    fn possibilities(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        use Tile::*;
+        match self.get(x, y).unwrap().0 {
+            Vert => vec![(0, -1), (0, 1)],
+            Hori => vec![(-1, 0), (1, 0)],
+            NE => vec![(0, -1), (1, 0)],
+            NW => vec![(0, -1), (-1, 0)],
+            SW => vec![(0, 1), (-1, 0)],
+            SE => vec![(0, 1), (1, 0)],
+            Start => vec![(0, -1), (0, 1), (1, 0), (-1, 0)],
+            Ground => vec![],
+        }.into_iter()
+            .filter_map(|offset| self.data.offset((x, y), offset))
+            .collect()
+    }
+    
+    pub fn neighbors(&self, x: usize, y: usize, from: Option<(usize, usize)>) -> Vec<(usize, usize)> {
+        let self_possibles = self.possibilities(x, y);
+        
+        let mut neighbors = Vec::with_capacity(3);
+        for possible in self_possibles {
+            let other_possibles = self.possibilities(possible.0, possible.1);
+            if other_possibles.contains(&(x, y)) {
+                neighbors.push(possible);
+            }
+        }
+        
+        if let Some((from_x, from_y)) = from {
+            for i in (0..neighbors.len()).rev() {
+                if neighbors[i].0 == from_x && neighbors[i].1 == from_y {
+                    neighbors.remove(i);
+                }
+            }
+        }
+        
+        neighbors
+    }
+}
+
+
+pub struct Day10;
+impl Solution for Day10 {
+    type Input<'a> = Maze;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Maze::new(input)
+    }
+    
+    fn part1(grid: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let path = walk_loop(grid)?;
+        
+        trace!("path: {path:?}");
+        
+        Ok((path.len() / 2).into())
+    }
+    
+    fn part2(maze: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let (grid, _) = fill(maze)?;
+        
+        let mut total = 0usize;
+        for y in 0..grid.data.height() {
+            for x in 0..grid.data.width() {
+                let cell = grid.get(x, y).unwrap();
+                let id = cell.1.unwrap();
+                if id > 0 && id != usize::MAX {
+                    total += 1;
+                }
+            }
+        }
+        
+        Ok(total.into())
+    }
+    
+    /// The loop in box-drawing characters, with the cells part 2 counts as enclosed shaded in.
+    fn render(maze: &Self::Input<'_>, colour: bool) -> Option<String> {
+        let (grid, path) = fill(maze).ok()?;
+        
+        let mut canvas = Canvas::from_grid(&grid.data, |_, &(tile, id)| match id {
+            Some(id) if id == usize::MAX => Cell::new(tile.into()),
+            Some(id) if id > 0 => Cell::new('░').fg(GREEN),
+            _ => Cell::new(' '),
+        });
+        canvas.recolour(path, YELLOW);
+        canvas.recolour([grid.start()], RED);
+        
+        Some(canvas.render(colour))
+    }
+}
+
+
+/// Every tile of the loop, from the first one after the start round to the start itself.
+fn walk_loop(maze: &Maze) -> Result<Vec<(usize, usize)>, ParseError> {
+    let start = maze.start();
+    
+    debug!("start: {start:?}");
+    
+    let mut current = maze.neighbors(start.0, start.1, None)[0];
+    let mut last = start;
+    
+    let mut path = Vec::with_capacity(128);
+    path.push(current);
+    while current != start {
+        let next = maze.neighbors(current.0, current.1, Some(last)).first().copied()
+            .ok_or_else(|| error_at(current, "a pipe continuing the loop", "a dead end"))?;
+        path.push(next);
+        last = current;
+        current = next;
+    }
+    
+    debug!("path len: {}", path.len());
+    
+    Ok(path)
+}
+
+/// Walks the loop, then flood fills every region off of it: those outside with 0, and each one the
+/// loop encloses with a unique id from 1.
+fn fill(maze: &Maze) -> Result<(Maze, Vec<(usize, usize)>), ParseError> {
+    let path = walk_loop(maze)?;
+    let mut grid = maze.clone();
+    
+    for (x, y) in &path {
+        grid.get_mut(*x, *y).unwrap().1 = Some(usize::MAX);
+    }
+    
+    let outside = outside(maze, &path);
+    let mut id = 1;
+    for y in 0..grid.data.height() {
+        for x in 0..grid.data.width() {
+            let cell = grid.get(x, y).unwrap();
+            if cell.1.is_some() { // cell has already been checked
+                continue;
+            }
+            
+            if outside[(x, y)] {
+                flood_fill(&mut grid, &path, x, y, 0);
+            } else {
+                flood_fill(&mut grid, &path, x, y, id);
+                id += 1;
+            }
+        }
+    }
+    
+    debug!("ids: {id}");
+    
+    Ok((grid, path))
+}
+
+
+/// The regions found by part 2 in green, each tinted differently, with the loop in white and the
+/// start in red.
+pub fn frame(maze: &Maze) -> Result<Frame, ParseError> {
+    let (grid, path) = fill(maze)?;
+    
+    let mut frame = Frame::from_grid(&grid.data, |_, &(_, id)| 0x00AA00 | (id.unwrap_or(0) % 256 * 47 % 256) as u32);
+    frame.overlay(path, 0xFFFFFF);
+    frame.overlay([grid.start()], 0xFF0000);
+    
+    Ok(frame)
+}
+
+/// Which tiles are outside of the loop, found by flood filling in from the border at double the
+/// resolution, so the fill can squeeze between pipes that run alongside each other.
+fn outside(maze: &Maze, path: &[(usize, usize)]) -> Grid<bool> {
+    // tile `(x, y)` sits at `(2x + 1, 2y + 1)`, with a gap all round the edge to start the fill from
+    let mut blocked = Grid::new(2 * maze.data.width() + 1, 2 * maze.data.height() + 1, false);
+    for (&(ax, ay), &(bx, by)) in std::iter::once(&maze.start()).chain(path).zip(path) {
+        blocked[(2 * ax + 1, 2 * ay + 1)] = true;
+        blocked[(ax + bx + 1, ay + by + 1)] = true;
+    }
+    
+    let mut reached = Grid::new(blocked.width(), blocked.height(), false);
+    let mut stack = vec![(0, 0)];
+    while let Some(pos) = stack.pop() {
+        if !blocked[pos] && !reached[pos] {
+            reached[pos] = true;
+            stack.extend(blocked.neighbors4(pos));
+        }
+    }
+    
+    Grid::from_vec(maze.data.width(), maze.data.positions().map(|(x, y)| reached[(2 * x + 1, 2 * y + 1)]).collect())
+}
+
+/// An error at the tile `(x, y)`.
+fn error_at((x, y): (usize, usize), expected: impl Into<String>, found: impl Display) -> ParseError {
+    ParseError::new(DAY, y + 1, x + 1, Unexpected::new(expected, found))
+}
+
+fn flood_fill(grid: &mut Maze, path: &[(usize, usize)], x: usize, y: usize, id: usize) {
+    let mut stack = vec![(x, y)];
+    while let Some((x, y)) = stack.pop() {
+        let cell = grid.get_mut(x, y).unwrap();
+        if cell.1.is_none() && !path.contains(&(x, y)) {
+            cell.1 = Some(id);
+            
+            stack.extend(grid.data.neighbors4((x, y)));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+use common::rng::Rng;
+
+/// A `size` by `size` image with a sparse scattering of galaxies, and at least two of them.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let side = size.max(2);
+    let density = 0.02 + rng.below(4) as f64 / 100.0;
+    
+    let mut image = vec![vec![b'.'; side]; side];
+    for row in &mut image {
+        for cell in row.iter_mut() {
+            if rng.chance(density) {
+                *cell = b'#';
+            }
+        }
+    }
+    while image.iter().flatten().filter(|&&cell| cell == b'#').count() < 2 {
+        image[rng.below(side)][rng.below(side)] = b'#';
+    }
+    
+    image.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+pub mod generator;
+
+use std::collections::HashMap;
+use common::answer::Answer;
+use common::error::{ParseError, Unexpected};
+use common::solution::Solution;
+use grid::Grid;
+use visual::terminal::{Canvas, Cell as Glyph, BLUE, DIM, YELLOW};
+
+const DAY: u8 = 11;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = include_str!("sample.txt");
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Cell {
+    galaxy_id: Option<usize>,
+}
+impl Cell {
+    pub fn new(galaxy_id: Option<usize>) -> Self {
+        Self {
+            galaxy_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    /// The image as it was given, before expanding.
+    original: Grid<Cell>,
+    /// `Vec<(x, y, id)>`
+    galaxies: Vec<(usize, usize, usize)>,
+}
+impl Image {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut counter = 1usize;
+        let mut grid = Grid::try_parse(DAY, input, |c| match c {
+            '.' => Ok(Cell::new(None)),
+            '#' => { let cell = Cell::new(Some(counter)); counter += 1; Ok(cell) },
+            _ => Err(Unexpected::new("`.` or `#`", c)),
+        })?;
+        let original = grid.clone();
+        
+        for row in (0..grid.height()).rev() {
+            let has_galaxy = grid.row(row).iter().any(|cell| cell.galaxy_id.is_some());
+            
+            if !has_galaxy {
+                grid.insert_row(row, grid.row(row).to_vec());
+            }
+        }
+        
+        for col in (0..grid.width()).rev() {
+            let has_galaxy = grid.column(col).any(|cell| cell.galaxy_id.is_some());
+            
+            if !has_galaxy {
+                grid.insert_column(col, vec![Cell::new(None); grid.height()]);
+            }
+        }
+        
+        let galaxies = grid.iter()
+            .filter_map(|((col, row), cell)| cell.galaxy_id.map(|id| (col, row, id)))
+            .collect();
+        
+        Ok(Self {
+            original,
+            galaxies,
+        })
+    }
+}
+
+
+pub struct Day11;
+impl Solution for Day11 {
+    /// The image with its empty space already expanded.
+    type Input<'a> = Image;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Image::new(input)
+    }
+    
+    fn part1(image: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut pairs = HashMap::with_capacity(256);
+        for lhs in &image.galaxies {
+            for rhs in &image.galaxies {
+                if lhs.2 != rhs.2 && !pairs.contains_key(&(rhs.2, lhs.2)) && !pairs.contains_key(&(lhs.2, rhs.2)) {
+                    let dist = rhs.0.abs_diff(lhs.0) + rhs.1.abs_diff(lhs.1);
+                    pairs.insert((lhs.2, rhs.2), dist);
+                }
+            }
+        }
+        
+        Ok(pairs.values().sum::<usize>().into())
+    }
+    
+    fn part2(_image: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        Ok(Answer::Unsolved)
+    }
+    
+    /// The image as given, with the empty rows and columns that get expanded shaded in.
+    fn render(image: &Self::Input<'_>, colour: bool) -> Option<String> {
+        let grid = &image.original;
+        let mut canvas = Canvas::from_grid(grid, |_, cell| match cell.galaxy_id {
+            Some(_) => Glyph::new('#').fg(YELLOW),
+            None => Glyph::new('.').fg(DIM),
+        });
+        
+        let empty_rows = (0..grid.height()).filter(|&y| grid.row(y).iter().all(|cell| cell.galaxy_id.is_none())).collect::<Vec<_>>();
+        let empty_columns = (0..grid.width()).filter(|&x| grid.column(x).all(|cell| cell.galaxy_id.is_none())).collect::<Vec<_>>();
+        canvas.highlight(empty_rows.iter().flat_map(|&y| (0..grid.width()).map(move |x| (x, y))), BLUE);
+        canvas.highlight(empty_columns.iter().flat_map(|&x| (0..grid.height()).map(move |y| (x, y))), BLUE);
+        
+        Some(canvas.render(colour))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,100 @@
+use common::rng::Rng;
+
+/// A line of reflection, numbered like the answers count rows or columns before it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Mirror {
+    Vertical(usize),
+    Horizontal(usize),
+}
+impl Mirror {
+    fn random(rng: &mut Rng, w: usize, h: usize) -> Self {
+        match rng.chance(0.5) {
+            true => Mirror::Vertical(rng.range(1..=w - 1)),
+            false => Mirror::Horizontal(rng.range(1..=h - 1)),
+        }
+    }
+    
+    /// Where `(x, y)` is reflected to, if that's still inside a `w` by `h` pattern.
+    fn reflect(self, (x, y): (usize, usize), w: usize, h: usize) -> Option<(usize, usize)> {
+        match self {
+            Mirror::Vertical(line) => (2 * line - 1).checked_sub(x).filter(|&x| x < w).map(|x| (x, y)),
+            Mirror::Horizontal(line) => (2 * line - 1).checked_sub(y).filter(|&y| y < h).map(|y| (x, y)),
+        }
+    }
+}
+
+/// `size` patterns, each with one line of reflection, and another that a single smudge breaks.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    
+    (0..size)
+        .map(|_| loop {
+            if let Some(pattern) = pattern(&mut rng) {
+                break pattern;
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Tries to make one pattern, giving up if the lines picked happen to make others.
+fn pattern(rng: &mut Rng) -> Option<String> {
+    let (w, h) = (rng.range(5..=17), rng.range(5..=17));
+    let clean = Mirror::random(rng, w, h);
+    let smudged = Mirror::random(rng, w, h);
+    if clean == smudged {
+        return None;
+    }
+    
+    // cells that either line maps onto each other have to match, so they're grouped together
+    let mut groups = (0..w * h).collect::<Vec<_>>();
+    for y in 0..h {
+        for x in 0..w {
+            for mirror in [clean, smudged] {
+                if let Some((rx, ry)) = mirror.reflect((x, y), w, h) {
+                    let (a, b) = (root(&mut groups, y * w + x), root(&mut groups, ry * w + rx));
+                    groups[a] = b;
+                }
+            }
+        }
+    }
+    
+    let values = (0..w * h).map(|_| rng.chance(0.5)).collect::<Vec<_>>();
+    let mut cells = (0..w * h).map(|cell| values[root(&mut groups, cell)]).collect::<Vec<_>>();
+    
+    // the smudge has to be reflected by the smudged line, and left alone by the clean one
+    let candidates = (0..w * h)
+        .map(|cell| (cell % w, cell / w))
+        .filter(|&pos| smudged.reflect(pos, w, h).is_some() && clean.reflect(pos, w, h).is_none())
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return None;
+    }
+    let (x, y) = *rng.choose(&candidates);
+    cells[y * w + x] = !cells[y * w + x];
+    
+    let mismatches = |mirror: Mirror| (0..w * h)
+        .filter(|&cell| mirror.reflect((cell % w, cell / w), w, h).is_some_and(|(rx, ry)| cells[cell] != cells[ry * w + rx]))
+        .count() / 2;
+    let lines = (1..w).map(Mirror::Vertical).chain((1..h).map(Mirror::Horizontal)).collect::<Vec<_>>();
+    let exact = lines.iter().filter(|&&mirror| mismatches(mirror) == 0).count();
+    let off_by_one = lines.iter().filter(|&&mirror| mismatches(mirror) == 1).count();
+    if exact != 1 || off_by_one != 1 {
+        return None;
+    }
+    
+    Some(cells.chunks(w)
+        .map(|row| row.iter().map(|&cell| if cell { '#' } else { '.' }).collect::<String>() + "\n")
+        .collect())
+}
+
+
+/// The cell standing for the group `cell` is in.
+fn root(groups: &mut [usize], mut cell: usize) -> usize {
+    while groups[cell] != cell {
+        groups[cell] = groups[groups[cell]];
+        cell = groups[cell];
+    }
+    
+    cell
+}
\ No newline at end of file
@@ -0,0 +1,336 @@
+pub mod generator;
+
+use common::answer::Answer;
+use common::error::{ParseError, Unexpected};
+use common::parse;
+use common::solution::{Solution, Variant};
+use common::warn;
+use grid::Grid;
+use visual::terminal::{Canvas, Cell, BLUE, DIM, RED};
+
+const DAY: u8 = 13;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+pub const SAMPLE: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    cells: Grid<bool>,
+}
+impl Pattern {
+    /// Errors are numbered by line within `chunk`.
+    pub fn new(chunk: &str) -> Result<Self, ParseError> {
+        let cells = Grid::try_parse(DAY, chunk, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(Unexpected::new("`#` or `.`", c)),
+        })?;
+        if cells.width() == 0 {
+            return Err(ParseError::new(DAY, 1, 1, Unexpected::new("a pattern", "")));
+        }
+        
+        Ok(Self { cells })
+    }
+    
+    
+    /// The number of columns left of the vertical line of reflection. If more than one line
+    /// reflects, such as in `###`, the leftmost wins.
+    pub fn check_vert_sym(&self) -> Option<usize> {
+        let mut found_lines: Vec<usize> = (0..(self.cells.width() - 1)).collect();
+        
+        for y in 0..self.cells.height() {
+            for i in (0..found_lines.len()).rev() {
+                if !Self::sym(self.cells.row(y), found_lines[i]) {
+                    found_lines.remove(i);
+                }
+            }
+        }
+        
+        found_lines.first().map(|num| *num + 1)
+    }
+    
+    /// The number of rows above the horizontal line of reflection. If more than one line reflects,
+    /// the topmost wins.
+    pub fn check_hori_sym(&self) -> Option<usize> {
+        let mut found_lines: Vec<usize> = (0..(self.cells.height() - 1)).collect();
+        
+        for x in 0..self.cells.width() {
+            let mut col = Vec::with_capacity(self.cells.height());
+            for y in 0..self.cells.height() {
+                col.push(self.cells[(x, y)]);
+            }
+            
+            for i in (0..found_lines.len()).rev() {
+                if !Self::sym(&col, found_lines[i]) {
+                    found_lines.remove(i);
+                }
+            }
+        }
+        
+        found_lines.first().map(|num| *num + 1)
+    }
+    
+    
+    pub fn check_vert_sym_smudge(&self) -> Option<usize> {
+        for y in 0..self.cells.height() {
+            for x in 0..self.cells.width() {
+                let mut grid = self.cells.clone();
+                grid[(x, y)] = !grid[(x, y)];
+                
+                
+                let mut found_lines_orig: Vec<usize> = (0..(self.cells.width() - 1)).collect();
+                for y in 0..self.cells.height() {
+                    for i in (0..found_lines_orig.len()).rev() {
+                        if !Self::sym(self.cells.row(y), found_lines_orig[i]) {
+                            found_lines_orig.remove(i);
+                        }
+                    }
+                }
+                
+                let mut found_lines_alt: Vec<usize> = (0..(self.cells.width() - 1)).collect();
+                for y in 0..self.cells.height() {
+                    for i in (0..found_lines_alt.len()).rev() {
+                        if !Self::sym(grid.row(y), found_lines_alt[i]) {
+                            found_lines_alt.remove(i);
+                        }
+                    }
+                }
+                
+                if found_lines_alt.is_empty() {
+                    continue;
+                }
+                
+                for alt in found_lines_alt {
+                    if found_lines_orig.contains(&alt) {
+                        continue;
+                    } else {
+                        return Some(alt + 1);
+                    }
+                }
+            }
+        }
+        
+        None
+    }
+    
+    pub fn check_hori_sym_smudge(&self) -> Option<usize> {
+        for y in 0..self.cells.height() {
+            for x in 0..self.cells.width() {
+                let mut grid = self.cells.clone();
+                grid[(x, y)] = !grid[(x, y)];
+                
+                
+                let mut found_lines_orig: Vec<usize> = (0..(self.cells.height() - 1)).collect();
+                for x in 0..self.cells.width() {
+                    let mut col = Vec::with_capacity(self.cells.height());
+                    for y in 0..self.cells.height() {
+                        col.push(self.cells[(x, y)]);
+                    }
+                    
+                    for i in (0..found_lines_orig.len()).rev() {
+                        if !Self::sym(&col, found_lines_orig[i]) {
+                            found_lines_orig.remove(i);
+                        }
+                    }
+                }
+                
+                
+                let mut found_lines_alt: Vec<usize> = (0..(self.cells.height() - 1)).collect();
+                for x in 0..self.cells.width() {
+                    let mut col = Vec::with_capacity(self.cells.height());
+                    for y in 0..self.cells.height() {
+                        col.push(grid[(x, y)]);
+                    }
+                    
+                    for i in (0..found_lines_alt.len()).rev() {
+                        if !Self::sym(&col, found_lines_alt[i]) {
+                            found_lines_alt.remove(i);
+                        }
+                    }
+                }
+                
+                if found_lines_alt.is_empty() {
+                    continue;
+                }
+                
+                for alt in found_lines_alt {
+                    if found_lines_orig.contains(&alt) {
+                        continue;
+                    } else {
+                        return Some(alt + 1);
+                    }
+                }
+            }
+        }
+        
+        None
+    }
+    
+    
+    /// Like [`Pattern::check_vert_sym_smudge`], but finds the line that exactly one pair of cells
+    /// keeps from being a reflection, instead of trying every smudge.
+    pub fn smudged_vert_sym(&self) -> Option<usize> {
+        (0..(self.cells.width() - 1))
+            .find(|&line| self.cells.rows().map(|row| Self::mismatches(row, line)).sum::<usize>() == 1)
+            .map(|line| line + 1)
+    }
+    
+    /// Like [`Pattern::check_hori_sym_smudge`], but finds the line that exactly one pair of cells
+    /// keeps from being a reflection, instead of trying every smudge.
+    pub fn smudged_hori_sym(&self) -> Option<usize> {
+        let columns = self.cells.columns().map(|col| col.copied().collect::<Vec<_>>()).collect::<Vec<_>>();
+        
+        (0..(self.cells.height() - 1))
+            .find(|&line| columns.iter().map(|col| Self::mismatches(col, line)).sum::<usize>() == 1)
+            .map(|line| line + 1)
+    }
+    
+    /// The pair of cells that keeps the line found by [`Pattern::smudged_vert_sym`] or
+    /// [`Pattern::smudged_hori_sym`] from being a reflection, one of which is the smudge.
+    pub fn smudge(&self) -> Option<[(usize, usize); 2]> {
+        let mismatched = |(x, y): (usize, usize), (rx, ry): (usize, usize)| {
+            (self.cells.contains((rx, ry)) && self.cells[(x, y)] != self.cells[(rx, ry)]).then_some([(x, y), (rx, ry)])
+        };
+        
+        if let Some(line) = self.smudged_vert_sym() {
+            return self.cells.positions().filter(|&(x, _)| x < line).find_map(|(x, y)| mismatched((x, y), (2 * line - 1 - x, y)));
+        }
+        let line = self.smudged_hori_sym()?;
+        self.cells.positions().filter(|&(_, y)| y < line).find_map(|(x, y)| mismatched((x, y), (x, 2 * line - 1 - y)))
+    }
+    
+    /// How many pairs of cells in `data` differ across the line between `lhs_i` and `lhs_i + 1`.
+    fn mismatches(data: &[bool], lhs_i: usize) -> usize {
+        data[..(lhs_i + 1)].iter().rev()
+            .zip(&data[(lhs_i + 1)..])
+            .filter(|(lhs, rhs)| lhs != rhs)
+            .count()
+    }
+    
+    /// Checks if `data` mirrors across the line between `lhs_i` and `lhs_i + 1`, which callers keep
+    /// within `data` by only trying lines up to `data.len() - 1`.
+    fn sym(data: &[bool], lhs_i: usize) -> bool {
+        let rhs_i = lhs_i + 1;
+        
+        // walking outwards from the line until either edge is reached
+        data[..rhs_i].iter().rev()
+            .zip(&data[rhs_i..])
+            .all(|(lhs, rhs)| lhs == rhs)
+    }
+}
+
+
+fn parse_patterns(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let blocks = parse::blocks(DAY, input);
+    if blocks.is_empty() {
+        return Err(ParseError::new(DAY, 1, 1, Unexpected::new("a pattern", "")));
+    }
+    
+    blocks.iter()
+        .map(|block| {
+            let chunk = block.lines.iter().map(|line| line.text).collect::<Vec<_>>().join("\n");
+            let before = block.lines[0].number - 1;
+            
+            Pattern::new(&chunk).map_err(|e| { let line = before + e.line; e.on_line(line) })
+        })
+        .collect()
+}
+
+pub struct Day13;
+impl Solution for Day13 {
+    type Input<'a> = Vec<Pattern>;
+    
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_patterns(input)
+    }
+    
+    fn part1(pats: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut total = 0usize;
+        for (i, pat) in pats.iter().enumerate() {
+            if let Some(num) = pat.check_vert_sym() {
+                total += num;
+            } else if let Some(num) = pat.check_hori_sym() {
+                total += 100 * num;
+            } else {
+                warn!("no sym found in pat {i}!");
+            }
+        }
+        
+        Ok(total.into())
+    }
+    
+    fn part2(pats: &Self::Input<'_>) -> Result<Answer, ParseError> {
+        let mut total = 0usize;
+        for (i, pat) in pats.iter().enumerate() {
+            if let Some(num) = pat.smudged_vert_sym() {
+                total += num;
+            } else if let Some(num) = pat.smudged_hori_sym() {
+                total += 100 * num;
+            } else {
+                warn!("no sym found in pat {i}!");
+            }
+        }
+        
+        Ok(total.into())
+    }
+    
+    /// Each pattern with its line of reflection shaded on both sides, and the smudge in red.
+    fn render(pats: &Self::Input<'_>, colour: bool) -> Option<String> {
+        let drawings = pats.iter()
+            .map(|pat| {
+                let mut canvas = Canvas::from_grid(&pat.cells, |_, &rock| match rock {
+                    true => Cell::new('#'),
+                    false => Cell::new('.').fg(DIM),
+                });
+                
+                if let Some(line) = pat.check_vert_sym() {
+                    canvas.highlight((0..pat.cells.height()).flat_map(|y| [(line - 1, y), (line, y)]), BLUE);
+                } else if let Some(line) = pat.check_hori_sym() {
+                    canvas.highlight((0..pat.cells.width()).flat_map(|x| [(x, line - 1), (x, line)]), BLUE);
+                }
+                if let Some(smudge) = pat.smudge() {
+                    canvas.highlight(smudge, RED);
+                }
+                
+                canvas.render(colour)
+            })
+            .collect::<Vec<_>>();
+        
+        Some(drawings.join("\n"))
+    }
+    
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant { name: "flipping", part: 2, solve: |pats| part2_flipping(pats) }]
+    }
+}
+
+/// Part 2 by flipping each cell in turn, and looking for a line that wasn't there before.
+fn part2_flipping(pats: &[Pattern]) -> Result<Answer, ParseError> {
+    let mut total = 0usize;
+    for (i, pat) in pats.iter().enumerate() {
+        if let Some(num) = pat.check_vert_sym_smudge() {
+            total += num;
+        } else if let Some(num) = pat.check_hori_sym_smudge() {
+            total += 100 * num;
+        } else {
+            warn!("no sym found in pat {i}!");
+        }
+    }
+    
+    Ok(total.into())
+}
\ No newline at end of file
@@ -0,0 +1,240 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use common::error::{Line, ParseError, Unexpected};
+
+/// Offsets of the four orthogonal neighbors, in `(dx, dy)` form.
+pub const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all eight surrounding neighbors, in `(dx, dy)` form.
+pub const OFFSETS_8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A rectangular grid of cells, stored row-major.
+///
+/// Positions are `(x, y)` tuples, with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+    
+    /// Builds a grid from row-major `cells`.
+    ///
+    /// Panics if `cells` cannot be split into rows of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells do not form rows of width {width}");
+        
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+    
+    /// Parses one row per line of `input`, mapping each char to a cell.
+    ///
+    /// Panics if the lines are not all the same length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) => assert_eq!(width, len, "row {y} is {len} wide, expected {width}"),
+            }
+        }
+        
+        Self::from_vec(width.unwrap_or(0), cells)
+    }
+    
+    /// Like [`Grid::parse`], but the mapping may fail, and ragged rows are reported instead of panicking.
+    pub fn try_parse(day: u8, input: &str, mut f: impl FnMut(char) -> Result<T, Unexpected>) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        
+        for line in Line::all(day, input) {
+            let before = cells.len();
+            for (i, c) in line.text.char_indices() {
+                cells.push(f(c).map_err(|e| line.locate(&line.text[i..], e))?);
+            }
+            
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(line.locate(line.text, Unexpected::new(format!("a row {width} cells wide"), format!("{len} cells"))));
+                },
+                Some(_) => {},
+            }
+        }
+        
+        Ok(Self::from_vec(width.unwrap_or(0), cells))
+    }
+    
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+    
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains((x, y)) {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+    
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains((x, y)) {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+    
+    /// Like [`Grid::get`], but negative coordinates are simply out of bounds.
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        
+        self.get(x as usize, y as usize)
+    }
+    
+    /// Moves `pos` by `(dx, dy)`, returning `None` if that leaves the grid.
+    pub fn offset(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        
+        self.contains(pos).then_some(pos)
+    }
+    
+    /// The in-bounds orthogonal neighbors of `pos`.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4.into_iter().filter_map(move |offset| self.offset(pos, offset))
+    }
+    
+    /// The in-bounds neighbors of `pos`, including diagonals.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8.into_iter().filter_map(move |offset| self.offset(pos, offset))
+    }
+    
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+    
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` panics on a zero size, and a zero-width grid has no cells anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+    
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+    
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+    
+    /// Every position in the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+    
+    /// Every cell alongside its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+    
+    /// The position of the first cell (in row-major order) matching `pred`.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells.iter()
+            .position(pred)
+            .map(|i| (i % self.width, i / self.width))
+    }
+    
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+    
+    /// Inserts a row before row `y`, shifting the rows below it down.
+    pub fn insert_row(&mut self, y: usize, row: Vec<T>) {
+        assert_eq!(row.len(), self.width, "row is {} wide, expected {}", row.len(), self.width);
+        assert!(y <= self.height, "row {y} is out of bounds");
+        
+        let at = y * self.width;
+        self.cells.splice(at..at, row);
+        self.height += 1;
+    }
+    
+    /// Inserts a column before column `x`, shifting the columns to its right over.
+    pub fn insert_column(&mut self, x: usize, column: Vec<T>) {
+        assert_eq!(column.len(), self.height, "column is {} tall, expected {}", column.len(), self.height);
+        assert!(x <= self.width, "column {x} is out of bounds");
+        
+        let width = self.width;
+        let mut old = std::mem::take(&mut self.cells).into_iter();
+        let mut cells = Vec::with_capacity((width + 1) * self.height);
+        for cell in column {
+            cells.extend(old.by_ref().take(x));
+            cells.push(cell);
+            cells.extend(old.by_ref().take(width - x));
+        }
+        
+        self.cells = cells;
+        self.width += 1;
+    }
+}
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+    
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| panic!("({x}, {y}) is outside of a {}x{} grid", self.width, self.height))
+    }
+}
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| panic!("({x}, {y}) is outside of a {width}x{height} grid"))
+    }
+}
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use common::error::Unexpected;
+use grid::Grid;
+
+fn numbered() -> Grid<u8> {
+    Grid::parse("012\n345\n678", |c| c as u8 - b'0')
+}
+
+#[test]
+fn signed_access_at_the_edges() {
+    let grid = numbered();
+    
+    assert_eq!(grid.get_signed(0, 0), Some(&0));
+    assert_eq!(grid.get_signed(2, 2), Some(&8));
+    assert_eq!(grid.get_signed(-1, 0), None);
+    assert_eq!(grid.get_signed(0, -1), None);
+    assert_eq!(grid.get_signed(3, 0), None);
+    assert_eq!(grid.get_signed(0, 3), None);
+    
+    assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    assert_eq!(grid.offset((2, 2), (0, 1)), None);
+    assert_eq!(grid.offset((1, 1), (1, -1)), Some((2, 0)));
+}
+
+#[test]
+fn neighbors_at_corners() {
+    let grid = numbered();
+    
+    assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbors4((2, 2)).collect::<Vec<_>>(), [(2, 1), (1, 2)]);
+    assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+    
+    assert_eq!(grid.neighbors8((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1), (1, 1)]);
+    assert_eq!(grid.neighbors8((2, 0)).collect::<Vec<_>>(), [(1, 0), (1, 1), (2, 1)]);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = numbered();
+    
+    assert_eq!(grid.row(1), [3, 4, 5]);
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [2, 5, 8]);
+    assert_eq!(grid.columns().map(|column| column.sum::<u8>()).collect::<Vec<_>>(), [9, 12, 15]);
+    assert_eq!(grid.position(|&cell| cell == 5), Some((2, 1)));
+    
+    let mut grid = grid;
+    grid.insert_row(1, vec![9, 9, 9]);
+    grid.insert_column(0, vec![7; 4]);
+    assert_eq!((grid.width(), grid.height()), (4, 4));
+    assert_eq!(grid.row(1), [7, 9, 9, 9]);
+    assert_eq!(grid.row(3), [7, 6, 7, 8]);
+}
+
+#[test]
+fn ragged_input() {
+    let e = Grid::try_parse(3, "...\n..\n...", Ok::<char, Unexpected>).unwrap_err();
+    assert_eq!((e.line, e.column), (2, 1));
+    assert_eq!(e.expected, "a row 3 cells wide");
+    
+    let e = Grid::try_parse(3, "..\n.x", |c| match c {
+        '.' => Ok(c),
+        _ => Err(Unexpected::new("`.`", c)),
+    }).unwrap_err();
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, "x"));
+    
+    assert_eq!(Grid::try_parse(3, "", Ok::<char, Unexpected>).unwrap().width(), 0);
+}
+
+#[test]
+#[should_panic(expected = "row 1 is 2 wide, expected 3")]
+fn parse_panics_on_ragged_input() {
+    Grid::parse("...\n..", |c| c);
+}
\ No newline at end of file
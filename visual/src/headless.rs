@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+use crate::{Frame, Visualizer};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+impl ImageFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            _ => None,
+        }
+    }
+    
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+    
+    pub fn encode(&self, frame: &Frame) -> Result<Vec<u8>, String> {
+        match self {
+            ImageFormat::Ppm => Ok(ppm(frame)),
+            ImageFormat::Png => png(frame),
+        }
+    }
+}
+
+/// A binary (`P6`) PPM image of `frame`.
+pub fn ppm(frame: &Frame) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", frame.width(), frame.height()).into_bytes();
+    image.extend(frame.rgb());
+    
+    image
+}
+
+pub fn png(frame: &Frame) -> Result<Vec<u8>, String> {
+    let mut image = vec![];
+    let mut encoder = png::Encoder::new(&mut image, frame.width() as u32, frame.height() as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    
+    encoder.write_header()
+        .and_then(|mut writer| writer.write_image_data(&frame.rgb()))
+        .map_err(|e| format!("failed to encode a png: {e}"))?;
+    
+    Ok(image)
+}
+
+/// Writes each frame to its own numbered image in a directory, for machines without a display.
+#[derive(Debug, Clone)]
+pub struct Headless {
+    dir: PathBuf,
+    format: ImageFormat,
+    written: usize,
+}
+impl Headless {
+    pub fn new(dir: PathBuf, format: ImageFormat) -> Self {
+        Self {
+            dir,
+            format,
+            written: 0,
+        }
+    }
+    
+    /// Where the next frame will be written.
+    pub fn next_path(&self) -> PathBuf {
+        self.dir.join(format!("frame-{:04}.{}", self.written, self.format.extension()))
+    }
+}
+impl Visualizer for Headless {
+    fn show(&mut self, frame: &Frame) -> Result<(), String> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| format!("failed to create {}: {e}", self.dir.display()))?;
+        
+        let path = self.next_path();
+        std::fs::write(&path, self.format.encode(frame)?).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+        self.written += 1;
+        
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+use grid::Grid;
+
+pub mod headless;
+pub mod terminal;
+#[cfg(feature = "window")]
+pub mod window;
+
+/// An image to show, with one `0xRRGGBB` pixel per grid cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub pixels: Grid<u32>,
+}
+impl Frame {
+    /// Colours each cell of `grid` with `colour`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut colour: impl FnMut((usize, usize), &T) -> u32) -> Self {
+        Self {
+            pixels: Grid::from_vec(grid.width(), grid.iter().map(|(pos, cell)| colour(pos, cell)).collect()),
+        }
+    }
+    
+    /// Paints over each of `cells` with `colour`, ignoring any outside of the frame.
+    pub fn overlay(&mut self, cells: impl IntoIterator<Item = (usize, usize)>, colour: u32) {
+        for (x, y) in cells {
+            if let Some(pixel) = self.pixels.get_mut(x, y) {
+                *pixel = colour;
+            }
+        }
+    }
+    
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+    
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+    
+    /// The pixels as `[r, g, b]` triples, row by row.
+    pub fn rgb(&self) -> Vec<u8> {
+        self.pixels.iter()
+            .flat_map(|(_, &pixel)| [(pixel >> 16) as u8, (pixel >> 8) as u8, pixel as u8])
+            .collect()
+    }
+}
+
+/// Somewhere a day can send frames to be seen.
+pub trait Visualizer {
+    /// Shows `frame`, after any frames shown before it.
+    fn show(&mut self, frame: &Frame) -> Result<(), String>;
+    
+    /// Called once the last frame has been shown, such as to keep a window open until it's closed.
+    fn finish(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Writes frames to `frames` if it's given, otherwise opens a window titled `title`.
+///
+/// Without the `window` feature there's no window to open, so this is `None` unless `frames` is given.
+pub fn open(title: &str, frames: Option<PathBuf>, format: headless::ImageFormat) -> Option<Box<dyn Visualizer>> {
+    if let Some(dir) = frames {
+        return Some(Box::new(headless::Headless::new(dir, format)));
+    }
+    
+    #[cfg(feature = "window")]
+    return Some(Box::new(window::Window::new(title)));
+    
+    #[cfg(not(feature = "window"))]
+    {
+        let _ = title;
+        None
+    }
+}
\ No newline at end of file
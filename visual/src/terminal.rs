@@ -0,0 +1,95 @@
+use std::fmt::Write;
+use grid::Grid;
+
+pub const DIM: u32 = 0x606060;
+pub const RED: u32 = 0xE05050;
+pub const GREEN: u32 = 0x50C050;
+pub const YELLOW: u32 = 0xE0C040;
+pub const BLUE: u32 = 0x3050A0;
+
+/// One character of a [`Canvas`], with optional `0xRRGGBB` colours.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Option<u32>,
+    pub bg: Option<u32>,
+}
+impl Cell {
+    pub fn new(glyph: char) -> Self {
+        Self {
+            glyph,
+            fg: None,
+            bg: None,
+        }
+    }
+    
+    pub fn fg(self, colour: u32) -> Self {
+        Self { fg: Some(colour), ..self }
+    }
+    
+    pub fn bg(self, colour: u32) -> Self {
+        Self { bg: Some(colour), ..self }
+    }
+}
+
+/// A grid of coloured characters, drawn with ANSI escape codes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    pub cells: Grid<Cell>,
+}
+impl Canvas {
+    /// Draws each cell of `grid` with `draw`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut draw: impl FnMut((usize, usize), &T) -> Cell) -> Self {
+        Self {
+            cells: Grid::from_vec(grid.width(), grid.iter().map(|(pos, cell)| draw(pos, cell)).collect()),
+        }
+    }
+    
+    /// Puts a `colour` background behind each of `cells`, ignoring any outside of the canvas.
+    pub fn highlight(&mut self, cells: impl IntoIterator<Item = (usize, usize)>, colour: u32) {
+        for (x, y) in cells {
+            if let Some(cell) = self.cells.get_mut(x, y) {
+                cell.bg = Some(colour);
+            }
+        }
+    }
+    
+    /// Draws each of `cells` in `colour`, ignoring any outside of the canvas.
+    pub fn recolour(&mut self, cells: impl IntoIterator<Item = (usize, usize)>, colour: u32) {
+        for (x, y) in cells {
+            if let Some(cell) = self.cells.get_mut(x, y) {
+                cell.fg = Some(colour);
+            }
+        }
+    }
+    
+    /// One line per row, with escape codes for the colours only if `colour` is set.
+    pub fn render(&self, colour: bool) -> String {
+        let mut output = String::with_capacity(self.cells.width() * self.cells.height() * 4);
+        
+        for row in self.cells.rows() {
+            let mut current = (None, None);
+            for cell in row {
+                // escapes are only written when the colours change, since most runs share them
+                if colour && (cell.fg, cell.bg) != current {
+                    output += "\x1b[0m";
+                    if let Some(fg) = cell.fg {
+                        write!(output, "\x1b[38;2;{};{};{}m", fg >> 16, (fg >> 8) & 0xFF, fg & 0xFF).unwrap();
+                    }
+                    if let Some(bg) = cell.bg {
+                        write!(output, "\x1b[48;2;{};{};{}m", bg >> 16, (bg >> 8) & 0xFF, bg & 0xFF).unwrap();
+                    }
+                    current = (cell.fg, cell.bg);
+                }
+                output.push(cell.glyph);
+            }
+            
+            if colour && current != (None, None) {
+                output += "\x1b[0m";
+            }
+            output.push('\n');
+        }
+        
+        output
+    }
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use std::time::Duration;
+use common::info;
+use minifb::{Key, MouseButton, MouseMode, Scale, ScaleMode, WindowOptions};
+use crate::{Frame, Visualizer};
+
+/// Shows frames in a window, scaled up so single cells can be seen.
+///
+/// The window opens with the first frame, sized to fit it, and stays open after the last one until
+/// it's closed or Escape is pressed.
+pub struct Window {
+    title: String,
+    window: Option<minifb::Window>,
+    buffer: Vec<u32>,
+    width: usize,
+    height: usize,
+}
+impl Window {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            window: None,
+            buffer: vec![],
+            width: 0,
+            height: 0,
+        }
+    }
+    
+    fn update(&mut self) -> Result<(), String> {
+        let window = self.window.as_mut().expect("no frame has been shown yet");
+        
+        window.update_with_buffer(&self.buffer, self.width, self.height).map_err(|e| format!("failed to update the window: {e}"))
+    }
+    
+    fn is_open(&self) -> bool {
+        self.window.as_ref().is_some_and(|window| window.is_open() && !window.is_key_down(Key::Escape))
+    }
+}
+impl Visualizer for Window {
+    fn show(&mut self, frame: &Frame) -> Result<(), String> {
+        if self.window.is_none() {
+            let mut window = minifb::Window::new(&self.title, frame.width(), frame.height(), WindowOptions {
+                scale: Scale::X4,
+                scale_mode: ScaleMode::Stretch,
+                ..WindowOptions::default()
+            }).map_err(|e| format!("failed to open a window: {e}"))?;
+            window.limit_update_rate(Some(Duration::from_micros(33333)));
+            
+            self.window = Some(window);
+        } else if !self.is_open() {
+            return Ok(());
+        }
+        
+        self.buffer = frame.pixels.iter().map(|(_, &pixel)| pixel).collect();
+        self.width = frame.width();
+        self.height = frame.height();
+        
+        self.update()
+    }
+    
+    fn finish(&mut self) -> Result<(), String> {
+        while self.is_open() {
+            let window = self.window.as_ref().unwrap();
+            if window.get_mouse_down(MouseButton::Left) {
+                if let Some((x, y)) = window.get_mouse_pos(MouseMode::Discard) {
+                    let (x, y) = (x as usize, y as usize);
+                    info!("({x}, {y}) is #{:06x}", self.buffer[y * self.width + x]);
+                }
+            }
+            
+            self.update()?;
+        }
+        
+        Ok(())
+    }
+}
\ No newline at end of file
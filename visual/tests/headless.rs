@@ -0,0 +1,66 @@
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use grid::Grid;
+use visual::headless::{ppm, Headless, ImageFormat};
+use visual::{Frame, Visualizer};
+
+/// A directory for a test's frames, removed again when it's dropped, even if the test fails.
+struct ScratchDir(PathBuf);
+impl ScratchDir {
+    /// Doesn't create the directory, so writing frames into it has to.
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("aoc-visual-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        
+        Self(dir)
+    }
+}
+impl Deref for ScratchDir {
+    type Target = Path;
+    
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn checkerboard() -> Frame {
+    let grid = Grid::parse("#.\n.#\n", |c| c == '#');
+    
+    Frame::from_grid(&grid, |_, &lit| if lit { 0xFFFFFF } else { 0x000000 })
+}
+
+#[test]
+fn frames_are_coloured_then_overlaid() {
+    let mut frame = checkerboard();
+    frame.overlay([(1, 0), (5, 5)], 0x123456);
+    
+    assert_eq!(frame.rgb(), [255, 255, 255, 0x12, 0x34, 0x56, 0, 0, 0, 255, 255, 255]);
+}
+
+#[test]
+fn ppm_is_a_binary_pixmap() {
+    let image = ppm(&checkerboard());
+    
+    assert!(image.starts_with(b"P6\n2 2\n255\n"));
+    assert_eq!(image.len(), b"P6\n2 2\n255\n".len() + 12);
+}
+
+#[test]
+fn headless_writes_numbered_frames() {
+    let dir = ScratchDir::new("numbered");
+    let mut headless = Headless::new(dir.to_path_buf(), ImageFormat::Png);
+    
+    headless.show(&checkerboard()).unwrap();
+    headless.show(&checkerboard()).unwrap();
+    headless.finish().unwrap();
+    
+    let png = std::fs::read(dir.join("frame-0001.png")).unwrap();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    assert!(dir.join("frame-0000.png").exists());
+    assert!(!dir.join("frame-0002.png").exists());
+}
\ No newline at end of file
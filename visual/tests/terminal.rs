@@ -0,0 +1,29 @@
+use grid::Grid;
+use visual::terminal::{Canvas, Cell};
+
+fn canvas() -> Canvas {
+    let grid = Grid::parse("#..\n.#.\n", |c| c);
+    
+    Canvas::from_grid(&grid, |_, &c| Cell::new(c))
+}
+
+#[test]
+fn plain_rendering_is_just_the_glyphs() {
+    let mut canvas = canvas();
+    canvas.highlight([(0, 0), (9, 9)], 0xFF0000);
+    canvas.recolour([(1, 1)], 0x00FF00);
+    
+    assert_eq!(canvas.render(false), "#..\n.#.\n");
+}
+
+#[test]
+fn colours_change_only_where_needed() {
+    let mut canvas = canvas();
+    canvas.highlight([(1, 0), (2, 0)], 0x102030);
+    canvas.recolour([(1, 1)], 0xFF8000);
+    
+    assert_eq!(canvas.render(true), concat!(
+        "#\x1b[0m\x1b[48;2;16;32;48m..\x1b[0m\n",
+        ".\x1b[0m\x1b[38;2;255;128;0m#\x1b[0m.\n",
+    ));
+}
\ No newline at end of file